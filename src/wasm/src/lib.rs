@@ -13,6 +13,20 @@ pub use time_series::unit_root_test::read_pvalue::*;
 pub use time_series::unit_root_test::dickey_fuller::dickey_fuller::DickeyFuller;
 pub use time_series::unit_root_test::augmented_dickey_fuller::augmented_dickey_fuller::AugmentedDickeyFuller;
 pub use time_series::arima::arima::Arima;
+pub use time_series::seasonality::seasonality::Seasonality;
+pub use time_series::cycle_filter::cycle_filter::CycleFilter;
+pub use time_series::outlier_detection::outlier_detection::OutlierDetection;
+pub use time_series::change_point::change_point::ChangePoint;
+pub use time_series::missing_value::missing_value::MissingValue;
+pub use time_series::time_index::time_index::TimeIndex;
+pub use time_series::resample::resample::Resample;
+pub use time_series::transformation::transformation::Transformation;
+pub use time_series::benchmark::benchmark::Benchmark;
+pub use time_series::forecast_combination::forecast_combination::ForecastCombination;
+pub use time_series::forecast_combination::forecaster::Forecaster;
+pub use time_series::reconciliation::reconciliation::Reconciliation;
+pub use time_series::structural_model::structural_model::StructuralModel;
+pub use time_series::granger_causality::granger_causality::GrangerCausality;
 
 pub mod regression;
 pub use regression::simple_linear_regression::simple_linear_regression::SimpleLinearRegression;
@@ -20,4 +34,6 @@ pub use regression::no_intercept_linear_regression::no_intercept_linear_regressi
 pub use regression::simple_exponential_regression::simple_exponential_regression::SimpleExponentialRegression;
 pub use regression::multiple_linear_regression::multiple_linear_regression::MultipleLinearRegression;
 pub use regression::quadratic_regression::quadratic_regression::QuadraticRegression;
+pub use regression::weight_estimation::weight_estimation::WeightEstimation;
+pub use regression::logistic_regression::logistic_regression::LogisticRegression;
 pub use regression::multiple_linear_regression::calculate_matrix::*;
@@ -0,0 +1,149 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect};
+use nalgebra::DMatrix;
+use statrs::distribution::{ContinuousCDF, StudentsT};
+use crate::regression::linear_model::linear_model::LinearModel;
+use crate::regression::linear_model::ols::fit_linear_model;
+
+// Two-sided Student t quantile of an interval at the given level, e.g. 0.95
+pub fn t_multiplier(level: f64, df: f64) -> f64 {
+    if !(0.0..1.0).contains(&level) || level == 0.0 {
+        panic!("Interval level must be between 0 and 1");
+    }
+    if df <= 0.0 {
+        return f64::NAN;
+    }
+    StudentsT::new(0.0, 1.0, df).unwrap().inverse_cdf((1.0 + level) / 2.0)
+}
+
+// Fitted values of new cases with the standard error of the mean response and the
+// bounds of its confidence interval and of the prediction interval of an individual case
+pub struct PredictionInterval {
+    pub fit: Vec<f64>,
+    pub se_fit: Vec<f64>,
+    pub mean_lower: Vec<f64>,
+    pub mean_upper: Vec<f64>,
+    pub individual_lower: Vec<f64>,
+    pub individual_upper: Vec<f64>,
+}
+
+impl PredictionInterval {
+    // Back to the original scale of a model fitted on the log of the response
+    pub fn exp(self) -> PredictionInterval {
+        let exp = |v: Vec<f64>| v.iter().map(|x| x.exp()).collect();
+        PredictionInterval {
+            fit: exp(self.fit),
+            se_fit: self.se_fit,
+            mean_lower: exp(self.mean_lower),
+            mean_upper: exp(self.mean_upper),
+            individual_lower: exp(self.individual_lower),
+            individual_upper: exp(self.individual_upper),
+        }
+    }
+
+    pub fn to_js(&self) -> JsValue {
+        let results = Object::new();
+        let set = |key: &str, values: &Vec<f64>| {
+            Reflect::set(&results, &key.into(), &serde_wasm_bindgen::to_value(values).unwrap()).unwrap();
+        };
+        set("Predicted", &self.fit);
+        set("Std. Error of Mean Prediction", &self.se_fit);
+        set("Lower Mean CI", &self.mean_lower);
+        set("Upper Mean CI", &self.mean_upper);
+        set("Lower Individual PI", &self.individual_lower);
+        set("Upper Individual PI", &self.individual_upper);
+        JsValue::from(results)
+    }
+}
+
+// A regression fitted by least squares on a design matrix built from its predictors; new
+// cases are given in the same form as the predictors
+pub trait LinearDesign {
+    type Predictors;
+
+    fn predictors(&self) -> Self::Predictors;
+
+    fn response(&self) -> Vec<f64>;
+
+    fn design(predictors: &Self::Predictors) -> DMatrix<f64>;
+
+    fn linear_model(&self) -> LinearModel {
+        fit_linear_model(&Self::design(&self.predictors()), &self.response())
+    }
+
+    // Confidence intervals of the coefficients at the given level as a table
+    fn confidence_interval_js(&self, level: f64) -> JsValue {
+        let (lower, upper) = self.linear_model().coefficient_interval(level);
+        interval_js(&lower, &upper)
+    }
+
+    fn predict_values(&self, new_x: &Self::Predictors) -> Vec<f64> {
+        self.linear_model().predict(&Self::design(new_x))
+    }
+
+    // Confidence interval of the mean response and prediction interval of new cases as a table
+    fn prediction_interval_js(&self, new_x: &Self::Predictors, level: f64) -> JsValue {
+        self.linear_model().prediction_interval(&Self::design(new_x), level).to_js()
+    }
+}
+
+// Lower and upper bounds of the coefficients as a table
+pub fn interval_js(lower: &Vec<f64>, upper: &Vec<f64>) -> JsValue {
+    let results = Object::new();
+    Reflect::set(&results, &"Lower Bound".into(), &serde_wasm_bindgen::to_value(lower).unwrap()).unwrap();
+    Reflect::set(&results, &"Upper Bound".into(), &serde_wasm_bindgen::to_value(upper).unwrap()).unwrap();
+    JsValue::from(results)
+}
+
+impl LinearModel {
+    // Confidence intervals of the coefficients, NaN for aliased columns
+    pub fn coefficient_interval(&self, level: f64) -> (Vec<f64>, Vec<f64>) {
+        self.coefficient_interval_from(&self.standard_error(), self.df_residual, level)
+    }
+
+    // Confidence intervals from other standard errors and t degrees of freedom, e.g. robust ones
+    pub fn coefficient_interval_from(&self, se: &[f64], df: f64, level: f64) -> (Vec<f64>, Vec<f64>) {
+        let t = t_multiplier(level, df);
+        let lower = self.coefficient.iter().zip(se.iter()).map(|(b, s)| b - t * s).collect();
+        let upper = self.coefficient.iter().zip(se.iter()).map(|(b, s)| b + t * s).collect();
+        (lower, upper)
+    }
+
+    // Fitted values of the rows of a new design matrix; aliased columns do not contribute
+    pub fn predict(&self, design: &DMatrix<f64>) -> Vec<f64> {
+        if design.ncols() != self.coefficient.len() {
+            panic!("New data must have {} design columns", self.coefficient.len());
+        }
+        (0..design.nrows())
+            .map(|i| (0..design.ncols()).filter(|j| !self.aliased[*j]).map(|j| design[(i, j)] * self.coefficient[j]).sum())
+            .collect()
+    }
+
+    // Mean response and individual prediction intervals at the rows of a new design matrix
+    pub fn prediction_interval(&self, design: &DMatrix<f64>, level: f64) -> PredictionInterval {
+        let fit = self.predict(design);
+        let mse = self.mse();
+        let t = t_multiplier(level, self.df_residual);
+        let kept: Vec<usize> = (0..design.ncols()).filter(|j| !self.aliased[*j]).collect();
+        let se_fit: Vec<f64> = (0..design.nrows())
+            .map(|i| {
+                let mut quadratic = 0.0;
+                for a in kept.iter() {
+                    for b in kept.iter() {
+                        quadratic += design[(i, *a)] * self.unscaled_covariance[(*a, *b)] * design[(i, *b)];
+                    }
+                }
+                (mse * quadratic).sqrt()
+            })
+            .collect();
+        let se_individual: Vec<f64> = se_fit.iter().map(|s| (s * s + mse).sqrt()).collect();
+        PredictionInterval {
+            mean_lower: fit.iter().zip(se_fit.iter()).map(|(f, s)| f - t * s).collect(),
+            mean_upper: fit.iter().zip(se_fit.iter()).map(|(f, s)| f + t * s).collect(),
+            individual_lower: fit.iter().zip(se_individual.iter()).map(|(f, s)| f - t * s).collect(),
+            individual_upper: fit.iter().zip(se_individual.iter()).map(|(f, s)| f + t * s).collect(),
+            fit,
+            se_fit,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use nalgebra::DMatrix;
+
+// Tolerance of the aliasing check: a column is aliased when less than this share of its
+// norm is left after projecting it on the columns kept before it
+pub const ALIAS_TOLERANCE: f64 = 1e-7;
+
+// Least squares fit of y on the columns of a design matrix. Aliased columns (linear
+// combinations of earlier columns) get a NaN coefficient and NaN rows and columns in
+// the unscaled covariance (X'X)^-1 of the estimable coefficients
+pub struct LinearModel {
+    pub coefficient: Vec<f64>,
+    pub aliased: Vec<bool>,
+    pub rank: usize,
+    pub fitted: Vec<f64>,
+    pub residual: Vec<f64>,
+    pub sse: f64,
+    pub df_residual: f64,
+    pub unscaled_covariance: DMatrix<f64>,
+    pub method: String, // "qr" or "svd" when the triangular solve broke down
+}
+
+impl LinearModel {
+    // Residual mean square
+    pub fn mse(&self) -> f64 {
+        self.sse / self.df_residual
+    }
+
+    // Standard errors of the coefficients, NaN for aliased columns
+    pub fn standard_error(&self) -> Vec<f64> {
+        let mse = self.mse();
+        (0..self.coefficient.len()).map(|i| (mse * self.unscaled_covariance[(i, i)]).sqrt()).collect()
+    }
+
+    // Column indices of the aliased columns
+    pub fn aliased_index(&self) -> Vec<usize> {
+        (0..self.aliased.len()).filter(|i| self.aliased[*i]).collect()
+    }
+
+    pub fn unscaled_covariance_rows(&self) -> Vec<Vec<f64>> {
+        (0..self.unscaled_covariance.nrows()).map(|i| self.unscaled_covariance.row(i).iter().cloned().collect()).collect()
+    }
+}
+
+// Design matrix of n rows from predictor columns, with a leading column of ones when
+// intercept is set; with no columns it is the constant alone
+pub fn design_matrix(n: usize, columns: &[Vec<f64>], intercept: bool) -> DMatrix<f64> {
+    let offset = if intercept { 1 } else { 0 };
+    if columns.iter().any(|c| c.len() != n) {
+        panic!("Predictor columns must have one value per row");
+    }
+    DMatrix::from_fn(n, columns.len() + offset, |i, j| if j < offset { 1.0 } else { columns[j - offset][i] })
+}
@@ -0,0 +1,4 @@
+pub mod linear_model;
+pub mod ols;
+pub mod interval;
+pub mod normality;
\ No newline at end of file
@@ -0,0 +1,78 @@
+use std::f64::consts::PI;
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+
+// Jarque-Bera statistic n/6 (S^2 + (K - 3)^2 / 4) from the moment skewness and kurtosis,
+// with its chi-square(2) p-value
+pub fn jarque_bera(x: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean = x.iter().sum::<f64>() / n;
+    let moment = |power: i32| x.iter().map(|v| (v - mean).powi(power)).sum::<f64>() / n;
+    let m2 = moment(2);
+    let skewness = moment(3) / m2.powf(1.5);
+    let kurtosis = moment(4) / (m2 * m2);
+    let statistic = n / 6.0 * (skewness.powi(2) + (kurtosis - 3.0).powi(2) / 4.0);
+    (statistic, 1.0 - ChiSquared::new(2.0).unwrap().cdf(statistic))
+}
+
+// Shapiro-Wilk W with Royston's (1995, AS R94) coefficients and normalizing transformation,
+// for 3 to 5000 observations
+pub fn shapiro_wilk(x: &[f64]) -> (f64, f64) {
+    let n = x.len();
+    if !(3..=5000).contains(&n) {
+        panic!("Shapiro-Wilk test needs between 3 and 5000 observations");
+    }
+    let mut sorted = x.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let nf = n as f64;
+
+    // Coefficients from the expected normal order statistics, the outer ones corrected
+    let m: Vec<f64> = (1..=n).map(|i| normal.inverse_cdf((i as f64 - 0.375) / (nf + 0.25))).collect();
+    let mm: f64 = m.iter().map(|v| v * v).sum();
+    let mut a = vec![0.0; n];
+    if n == 3 {
+        a[0] = -(0.5f64).sqrt();
+        a[2] = (0.5f64).sqrt();
+    } else {
+        let u = 1.0 / nf.sqrt();
+        let polynomial = |c: &[f64]| c.iter().enumerate().map(|(i, ci)| ci * u.powi(i as i32 + 1)).sum::<f64>();
+        let an = m[n - 1] / mm.sqrt() + polynomial(&[0.221157, -0.147981, -2.071190, 4.434685, -2.706056]);
+        if n > 5 {
+            let an1 = m[n - 2] / mm.sqrt() + polynomial(&[0.042981, -0.293762, -1.752461, 5.682633, -3.582633]);
+            let phi = (mm - 2.0 * m[n - 1].powi(2) - 2.0 * m[n - 2].powi(2)) / (1.0 - 2.0 * an.powi(2) - 2.0 * an1.powi(2));
+            for i in 2..n - 2 {
+                a[i] = m[i] / phi.sqrt();
+            }
+            a[1] = -an1;
+            a[n - 2] = an1;
+        } else {
+            let phi = (mm - 2.0 * m[n - 1].powi(2)) / (1.0 - 2.0 * an.powi(2));
+            for i in 1..n - 1 {
+                a[i] = m[i] / phi.sqrt();
+            }
+        }
+        a[0] = -an;
+        a[n - 1] = an;
+    }
+    let mean = sorted.iter().sum::<f64>() / nf;
+    let ss: f64 = sorted.iter().map(|v| (v - mean).powi(2)).sum();
+    let w = a.iter().zip(sorted.iter()).map(|(ai, xi)| ai * xi).sum::<f64>().powi(2) / ss;
+
+    let p_value = if n == 3 {
+        (6.0 / PI * (w.sqrt().asin() - (0.75f64).sqrt().asin())).max(0.0)
+    } else {
+        let z = if n <= 11 {
+            let gamma = -2.273 + 0.459 * nf;
+            let mu = 0.5440 - 0.39978 * nf + 0.025054 * nf.powi(2) - 0.0006714 * nf.powi(3);
+            let sigma = (1.3822 - 0.77857 * nf + 0.062767 * nf.powi(2) - 0.0020322 * nf.powi(3)).exp();
+            (-(gamma - (1.0 - w).ln()).ln() - mu) / sigma
+        } else {
+            let ln_n = nf.ln();
+            let mu = -1.5861 - 0.31082 * ln_n - 0.083751 * ln_n.powi(2) + 0.0038915 * ln_n.powi(3);
+            let sigma = (-0.4803 - 0.082676 * ln_n + 0.0030302 * ln_n.powi(2)).exp();
+            ((1.0 - w).ln() - mu) / sigma
+        };
+        1.0 - normal.cdf(z)
+    };
+    (w, p_value)
+}
@@ -0,0 +1,219 @@
+use nalgebra::{DMatrix, DVector};
+use crate::regression::linear_model::linear_model::{LinearModel, ALIAS_TOLERANCE};
+
+// Columns aliased with the columns before them, checked in order so that the earlier of
+// two collinear columns is kept
+pub fn aliased_columns(x: &DMatrix<f64>) -> Vec<bool> {
+    let mut kept: Vec<usize> = Vec::new();
+    let mut aliased = vec![false; x.ncols()];
+    for (j, flag) in aliased.iter_mut().enumerate() {
+        let column = x.column(j).into_owned();
+        let norm = column.norm();
+        let remainder = if kept.is_empty() {
+            norm
+        } else {
+            let q = x.select_columns(kept.iter()).qr().q();
+            (&column - &q * (q.transpose() * &column)).norm()
+        };
+        if norm == 0.0 || remainder <= ALIAS_TOLERANCE * norm {
+            *flag = true;
+        } else {
+            kept.push(j);
+        }
+    }
+    aliased
+}
+
+// Coefficients and unscaled covariance of a full column rank design, by QR with column
+// pivoting, or by the SVD pseudo-inverse when the triangular factor cannot be inverted
+fn solve_full_rank(x: &DMatrix<f64>, y: &DVector<f64>) -> (DVector<f64>, DMatrix<f64>, String) {
+    let k = x.ncols();
+    let qr = x.clone().col_piv_qr();
+    let r = qr.r();
+    if let Some(r_inverse) = r.solve_upper_triangular(&DMatrix::identity(k, k)) {
+        let q = qr.q();
+        let pivoted = &r_inverse * (q.transpose() * y);
+        let pivoted_covariance = &r_inverse * r_inverse.transpose();
+        if pivoted.iter().all(|b| b.is_finite()) {
+            // Undo the column permutation: position i of the pivoted order is column order[i]
+            let mut index = DMatrix::from_fn(1, k, |_, j| j as f64);
+            qr.p().permute_columns(&mut index);
+            let order: Vec<usize> = index.iter().map(|v| *v as usize).collect();
+            let mut coefficient = DVector::zeros(k);
+            let mut covariance = DMatrix::zeros(k, k);
+            for a in 0..k {
+                coefficient[order[a]] = pivoted[a];
+                for b in 0..k {
+                    covariance[(order[a], order[b])] = pivoted_covariance[(a, b)];
+                }
+            }
+            return (coefficient, covariance, "qr".to_string());
+        }
+    }
+    let pseudo_inverse = x.clone().svd(true, true).pseudo_inverse(f64::EPSILON * k as f64).unwrap();
+    let coefficient = &pseudo_inverse * y;
+    let covariance = &pseudo_inverse * pseudo_inverse.transpose();
+    (coefficient, covariance, "svd".to_string())
+}
+
+// Ordinary least squares on the design matrix, reporting aliased columns instead of failing
+pub fn fit_linear_model(x: &DMatrix<f64>, y: &[f64]) -> LinearModel {
+    let (n, k) = (x.nrows(), x.ncols());
+    if y.len() != n {
+        panic!("Response and design matrix must have the same number of rows");
+    }
+    let y_vector = DVector::from_column_slice(y);
+    let aliased = aliased_columns(x);
+    let kept: Vec<usize> = (0..k).filter(|j| !aliased[*j]).collect();
+    let rank = kept.len();
+
+    let mut coefficient = vec![f64::NAN; k];
+    let mut unscaled_covariance = DMatrix::from_element(k, k, f64::NAN);
+    let mut fitted = vec![0.0; n];
+    let mut method = "qr".to_string();
+    if rank > 0 {
+        let x_kept = x.select_columns(kept.iter());
+        let (b, covariance, solver) = solve_full_rank(&x_kept, &y_vector);
+        for (a, i) in kept.iter().enumerate() {
+            coefficient[*i] = b[a];
+            for (c, j) in kept.iter().enumerate() {
+                unscaled_covariance[(*i, *j)] = covariance[(a, c)];
+            }
+        }
+        fitted = (&x_kept * &b).iter().cloned().collect();
+        method = solver;
+    }
+    let residual: Vec<f64> = y.iter().zip(fitted.iter()).map(|(y, f)| y - f).collect();
+    let sse = residual.iter().map(|e| e * e).sum();
+    LinearModel {
+        coefficient,
+        aliased,
+        rank,
+        fitted,
+        residual,
+        sse,
+        df_residual: (n - rank) as f64,
+        unscaled_covariance,
+        method,
+    }
+}
+
+// Weighted least squares with the rows of the design and response scaled by the square roots
+// of the case weights, so sse is the weighted residual sum of squares. Fitted values and
+// residuals are on the original scale and zero-weight cases add no residual degree of freedom
+pub fn fit_weighted_linear_model(x: &DMatrix<f64>, y: &[f64], weight: &[f64]) -> LinearModel {
+    let (n, k) = (x.nrows(), x.ncols());
+    if weight.len() != n || y.len() != n {
+        panic!("Response, weights and design matrix must have the same number of rows");
+    }
+    if weight.iter().any(|w| *w < 0.0 || w.is_nan()) {
+        panic!("Case weights must be nonnegative");
+    }
+    let root: Vec<f64> = weight.iter().map(|w| w.sqrt()).collect();
+    let scaled = DMatrix::from_fn(n, k, |i, j| x[(i, j)] * root[i]);
+    let scaled_y: Vec<f64> = y.iter().zip(root.iter()).map(|(v, r)| v * r).collect();
+    let mut model = fit_linear_model(&scaled, &scaled_y);
+    model.fitted = if model.rank > 0 { model.predict(x) } else { vec![0.0; n] };
+    model.residual = y.iter().zip(model.fitted.iter()).map(|(v, f)| v - f).collect();
+    model.df_residual = (weight.iter().filter(|w| **w > 0.0).count() - model.rank) as f64;
+    model
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fit_linear_model;
+    use crate::regression::linear_model::linear_model::design_matrix;
+
+    // Number of correct significant digits of an estimate against a certified value
+    fn log_relative_error(estimate: f64, certified: f64) -> f64 {
+        if estimate == certified {
+            return 15.0;
+        }
+        if certified == 0.0 {
+            return -estimate.abs().log10();
+        }
+        -((estimate - certified).abs() / certified.abs()).log10()
+    }
+
+    fn check(estimate: &[f64], certified: &[f64], digits: f64) {
+        for (e, c) in estimate.iter().zip(certified.iter()) {
+            assert!(log_relative_error(*e, *c) >= digits, "estimate {} against certified {}", e, c);
+        }
+    }
+
+    // NIST StRD Wampler designs: x = 0..20 and its powers up to 5
+    fn wampler(y: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        let x: Vec<f64> = (0..=20).map(|i| i as f64).collect();
+        let columns: Vec<Vec<f64>> = (1..=5).map(|p| x.iter().map(|v| v.powi(p)).collect()).collect();
+        let model = fit_linear_model(&design_matrix(x.len(), &columns, true), y);
+        (model.coefficient.clone(), model.standard_error())
+    }
+
+    #[test]
+    fn longley() {
+        let data: [[f64; 7]; 16] = [
+            [60323.0, 83.0, 234289.0, 2356.0, 1590.0, 107608.0, 1947.0],
+            [61122.0, 88.5, 259426.0, 2325.0, 1456.0, 108632.0, 1948.0],
+            [60171.0, 88.2, 258054.0, 3682.0, 1616.0, 109773.0, 1949.0],
+            [61187.0, 89.5, 284599.0, 3351.0, 1650.0, 110929.0, 1950.0],
+            [63221.0, 96.2, 328975.0, 2099.0, 3099.0, 112075.0, 1951.0],
+            [63639.0, 98.1, 346999.0, 1932.0, 3594.0, 113270.0, 1952.0],
+            [64989.0, 99.0, 365385.0, 1870.0, 3547.0, 115094.0, 1953.0],
+            [63761.0, 100.0, 363112.0, 3578.0, 3350.0, 116219.0, 1954.0],
+            [66019.0, 101.2, 397469.0, 2904.0, 3048.0, 117388.0, 1955.0],
+            [67857.0, 104.6, 419180.0, 2822.0, 2857.0, 118734.0, 1956.0],
+            [68169.0, 108.4, 442769.0, 2936.0, 2798.0, 120445.0, 1957.0],
+            [66513.0, 110.8, 444546.0, 4681.0, 2637.0, 121950.0, 1958.0],
+            [68655.0, 112.6, 482704.0, 3813.0, 2552.0, 123366.0, 1959.0],
+            [69564.0, 114.2, 502601.0, 3931.0, 2514.0, 125368.0, 1960.0],
+            [69331.0, 115.7, 518173.0, 4806.0, 2572.0, 127852.0, 1961.0],
+            [70551.0, 116.9, 554894.0, 4007.0, 2827.0, 130081.0, 1962.0],
+        ];
+        let y: Vec<f64> = data.iter().map(|row| row[0]).collect();
+        let columns: Vec<Vec<f64>> = (1..7).map(|j| data.iter().map(|row| row[j]).collect()).collect();
+        let model = fit_linear_model(&design_matrix(y.len(), &columns, true), &y);
+        check(
+            &model.coefficient,
+            &[-3482258.63459582, 15.0618722713733, -0.358191792925910E-01, -2.02022980381683, -1.03322686717359, -0.511041056535807E-01, 1829.15146461355],
+            10.0,
+        );
+        check(
+            &model.standard_error(),
+            &[890420.383607373, 84.9149257747669, 0.334910077722432E-01, 0.488399681651699, 0.214274163161675, 0.226073200069370, 455.478499142212],
+            10.0,
+        );
+        check(&[model.mse().sqrt()], &[304.854073561965], 10.0);
+    }
+
+    #[test]
+    fn wampler1() {
+        let y: Vec<f64> = (0..=20).map(|i| (0..=5).map(|p| (i as f64).powi(p)).sum()).collect();
+        let (coefficient, standard_error) = wampler(&y);
+        check(&coefficient, &[1.0; 6], 8.0);
+        assert!(standard_error.iter().all(|se| se.abs() < 1e-6));
+    }
+
+    #[test]
+    fn wampler4() {
+        let y = [
+            75901.0, -204794.0, 204863.0, -204436.0, 253665.0, -200894.0, 214131.0, -185192.0, 221249.0, -138370.0, 315911.0,
+            -27644.0, 455253.0, 197434.0, 783995.0, 608816.0, 1370781.0, 1303798.0, 2205519.0, 2408860.0, 3444321.0,
+        ];
+        let (coefficient, standard_error) = wampler(&y);
+        check(&coefficient, &[1.0; 6], 8.0);
+        check(&standard_error, &[215232.624678170, 236355.173469681, 77934.3524331583, 10147.5507550350, 564.566512170752, 11.2324854679312], 10.0);
+    }
+
+    #[test]
+    fn wampler5() {
+        let y = [
+            7590001.0, -20479994.0, 20480063.0, -20479636.0, 25231365.0, -20476094.0, 20489331.0, -20460392.0, 18417449.0,
+            -20413570.0, 20591111.0, -20302844.0, 18651453.0, -20077766.0, 21059195.0, -19666384.0, 26348481.0, -18971402.0,
+            22480719.0, -17866340.0, 10958421.0,
+        ];
+        let (coefficient, standard_error) = wampler(&y);
+        // The disturbance swamps the signal (R-squared 0.002), so fewer digits survive
+        check(&coefficient, &[1.0; 6], 6.0);
+        check(&standard_error, &[21523262.4678170, 23635517.3469681, 7793435.24331583, 1014755.07550350, 56456.6512170752, 1123.24854679312], 10.0);
+    }
+}
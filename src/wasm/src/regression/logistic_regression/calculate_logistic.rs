@@ -0,0 +1,124 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use nalgebra::DMatrix;
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use crate::LogisticRegression;
+use crate::regression::logistic_regression::irls::{fit_logistic, LogisticFit};
+
+// Upper tail probability of a chi-square statistic
+pub fn chi_square_p_value(statistic: f64, df: f64) -> f64 {
+    if df <= 0.0 || !statistic.is_finite() {
+        return f64::NAN;
+    }
+    ChiSquared::new(df).unwrap().sf(statistic.max(0.0))
+}
+
+// Wald statistic b' V^-1 b of a set of design columns, skipping aliased ones, with its df
+pub fn wald_statistic(fit: &LogisticFit, columns: &[usize]) -> (f64, usize) {
+    let kept: Vec<usize> = columns.iter().filter(|j| !fit.aliased[**j]).cloned().collect();
+    if kept.is_empty() {
+        return (f64::NAN, 0);
+    }
+    let b = DMatrix::from_fn(kept.len(), 1, |i, _| fit.coefficient[kept[i]]);
+    let v = DMatrix::from_fn(kept.len(), kept.len(), |a, c| fit.covariance[(kept[a], kept[c])]);
+    let inverse = v.clone().try_inverse().unwrap_or_else(|| v.pseudo_inverse(1e-12).unwrap());
+    ((b.transpose() * inverse * b)[(0, 0)], kept.len())
+}
+
+impl LogisticRegression {
+    pub fn fit(&self, selected: &[usize]) -> LogisticFit {
+        let (design, _, _) = self.design(selected);
+        fit_logistic(&design, &self.get_y())
+    }
+
+    // Fit the selected covariates and keep the estimates
+    pub fn store_fit(&mut self, selected: Vec<usize>) -> LogisticFit {
+        let fit = self.fit(&selected);
+        let (_, names, _) = self.design(&selected);
+        self.set_selected(selected);
+        self.set_parameter_name(names);
+        self.set_coefficient(fit.coefficient.clone());
+        self.set_covariance(fit.covariance_rows());
+        self.set_probability(fit.probability.clone());
+        self.set_log_likelihood(fit.log_likelihood);
+        self.set_iteration(fit.iteration);
+        self.set_converged(fit.converged);
+        self.set_separation(fit.separation.clone());
+        fit
+    }
+
+    // Number of estimable parameters of the stored fit
+    pub fn rank(&self) -> usize {
+        self.get_coefficient().iter().filter(|b| !b.is_nan()).count()
+    }
+}
+
+#[wasm_bindgen]
+impl LogisticRegression {
+    // Variables in the Equation: B, S.E., Wald, df, Sig., Exp(B) and the confidence interval
+    // of Exp(B) at level. A categorical covariate gets an overall Wald row before its contrasts
+    // and the constant comes last
+    pub fn calculate_coefficients(&self, level: f64) -> JsValue {
+        if level <= 0.0 || level >= 1.0 {
+            panic!("Interval level must be between 0 and 1");
+        }
+        let z = Normal::new(0.0, 1.0).unwrap().inverse_cdf((1.0 + level) / 2.0);
+        let selected = self.get_selected();
+        let (_, names, owner) = self.design(&selected);
+        let fit = self.fit(&selected);
+        let se = fit.standard_error();
+        let rows = Array::new();
+        let parameter_row = |j: usize| {
+            let b = fit.coefficient[j];
+            let wald = (b / se[j]).powi(2);
+            let row = Object::new();
+            Reflect::set(&row, &"Variable".into(), &names[j].clone().into()).unwrap();
+            Reflect::set(&row, &"B".into(), &b.into()).unwrap();
+            Reflect::set(&row, &"S.E.".into(), &se[j].into()).unwrap();
+            Reflect::set(&row, &"Wald".into(), &wald.into()).unwrap();
+            Reflect::set(&row, &"df".into(), &1.0.into()).unwrap();
+            Reflect::set(&row, &"Sig.".into(), &chi_square_p_value(wald, 1.0).into()).unwrap();
+            Reflect::set(&row, &"Exp(B)".into(), &b.exp().into()).unwrap();
+            Reflect::set(&row, &"Lower".into(), &(b - z * se[j]).exp().into()).unwrap();
+            Reflect::set(&row, &"Upper".into(), &(b + z * se[j]).exp().into()).unwrap();
+            row
+        };
+        for variable in selected.iter() {
+            let columns: Vec<usize> = (0..owner.len()).filter(|j| owner[*j] == Some(*variable)).collect();
+            if self.get_coding()[*variable] != "none" {
+                let (wald, df) = wald_statistic(&fit, &columns);
+                let row = Object::new();
+                Reflect::set(&row, &"Variable".into(), &self.get_x_header()[*variable].clone().into()).unwrap();
+                Reflect::set(&row, &"Wald".into(), &wald.into()).unwrap();
+                Reflect::set(&row, &"df".into(), &(df as f64).into()).unwrap();
+                Reflect::set(&row, &"Sig.".into(), &chi_square_p_value(wald, df as f64).into()).unwrap();
+                rows.push(&row);
+            }
+            for j in columns {
+                rows.push(&parameter_row(j));
+            }
+        }
+        rows.push(&parameter_row(0));
+        JsValue::from(rows)
+    }
+
+    // Model Summary with the omnibus test of the model against the constant only
+    pub fn calculate_model_summary(&self) -> JsValue {
+        let n = self.get_y().len() as f64;
+        let (log_likelihood, null_log_likelihood) = (self.get_log_likelihood(), self.get_null_log_likelihood());
+        let cox_snell = 1.0 - (2.0 / n * (null_log_likelihood - log_likelihood)).exp();
+        let nagelkerke = cox_snell / (1.0 - (2.0 / n * null_log_likelihood).exp());
+        let chi_square = 2.0 * (log_likelihood - null_log_likelihood);
+        let df = self.rank() as f64 - 1.0;
+        let results = Object::new();
+        Reflect::set(&results, &"-2 Log likelihood".into(), &(-2.0 * log_likelihood).into()).unwrap();
+        Reflect::set(&results, &"Cox & Snell R Square".into(), &cox_snell.into()).unwrap();
+        Reflect::set(&results, &"Nagelkerke R Square".into(), &nagelkerke.into()).unwrap();
+        Reflect::set(&results, &"Chi-square".into(), &chi_square.into()).unwrap();
+        Reflect::set(&results, &"df".into(), &df.into()).unwrap();
+        Reflect::set(&results, &"Sig.".into(), &chi_square_p_value(chi_square, df).into()).unwrap();
+        Reflect::set(&results, &"Iterations".into(), &(self.get_iteration() as f64).into()).unwrap();
+        Reflect::set(&results, &"Separation".into(), &self.get_separation().into()).unwrap();
+        JsValue::from(results)
+    }
+}
@@ -0,0 +1,70 @@
+use nalgebra::DMatrix;
+use crate::LogisticRegression;
+
+// Distinct values of a covariate in increasing order
+pub fn categories(v: &[f64]) -> Vec<f64> {
+    let mut value = v.to_vec();
+    value.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    value.dedup();
+    value
+}
+
+// Contrast columns of a categorical covariate, one per category other than the reference.
+// Indicator codes the category 1 and others 0; deviation also codes the reference -1; simple
+// codes the category (k - 1) / k and every other category, the reference included, -1 / k
+pub fn contrast_columns(v: &[f64], coding: &str, reference: &str) -> Vec<Vec<f64>> {
+    let category = categories(v);
+    let k = category.len();
+    if k < 2 {
+        panic!("Categorical covariate needs at least two categories");
+    }
+    let reference_index = if reference == "first" { 0 } else { k - 1 };
+    (0..k)
+        .filter(|c| *c != reference_index)
+        .map(|c| {
+            v.iter()
+                .map(|value| {
+                    let own = if *value == category[c] { 1.0 } else { 0.0 };
+                    match coding {
+                        "indicator" => own,
+                        "deviation" => if *value == category[reference_index] { -1.0 } else { own },
+                        "simple" => own - 1.0 / k as f64,
+                        _ => panic!("Unknown contrast coding: {}", coding),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+impl LogisticRegression {
+    // Design columns of covariate j with their names, header(1), header(2), ... when categorical
+    pub fn variable_columns(&self, j: usize) -> (Vec<Vec<f64>>, Vec<String>) {
+        let x = &self.get_x_values()[j];
+        let header = &self.get_x_header()[j];
+        let coding = &self.get_coding()[j];
+        if coding == "none" {
+            return (vec![x.clone()], vec![header.clone()]);
+        }
+        let columns = contrast_columns(x, coding, &self.get_reference()[j]);
+        let names = (1..=columns.len()).map(|c| format!("{}({})", header, c)).collect();
+        (columns, names)
+    }
+
+    // Design matrix of the constant and the selected covariates with the column names and
+    // the covariate of each column (None for the constant)
+    pub fn design(&self, selected: &[usize]) -> (DMatrix<f64>, Vec<String>, Vec<Option<usize>>) {
+        let n = self.get_y().len();
+        let mut columns = vec![vec![1.0; n]];
+        let mut names = vec!["Constant".to_string()];
+        let mut owner = vec![None];
+        for j in selected {
+            let (column, name) = self.variable_columns(*j);
+            owner.extend(vec![Some(*j); column.len()]);
+            columns.extend(column);
+            names.extend(name);
+        }
+        let design = DMatrix::from_fn(n, columns.len(), |i, j| columns[j][i]);
+        (design, names, owner)
+    }
+}
@@ -0,0 +1,103 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use crate::LogisticRegression;
+use crate::regression::logistic_regression::calculate_logistic::chi_square_p_value;
+
+// One group of the Hosmer-Lemeshow table: size with the observed and expected counts of y = 0 and y = 1
+pub struct HosmerLemeshowGroup {
+    pub total: f64,
+    pub observed: [f64; 2],
+    pub expected: [f64; 2],
+}
+
+impl LogisticRegression {
+    // Cases sorted by predicted probability split into about ten groups of equal size, cases
+    // with tied probabilities kept in the same group
+    pub fn hosmer_lemeshow_groups(&self) -> Vec<HosmerLemeshowGroup> {
+        let probability = self.get_probability();
+        let y = self.get_y();
+        let n = probability.len();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|a, b| probability[*a].partial_cmp(&probability[*b]).unwrap());
+        let mut groups = Vec::new();
+        let mut start = 0;
+        for g in 1..=10 {
+            if start >= n {
+                break;
+            }
+            let mut end = ((n * g) as f64 / 10.0).round() as usize;
+            end = end.max(start + 1).min(n);
+            while end < n && probability[order[end]] == probability[order[end - 1]] {
+                end += 1;
+            }
+            let members = &order[start..end];
+            let observed1: f64 = members.iter().map(|i| y[*i]).sum();
+            let expected1: f64 = members.iter().map(|i| probability[*i]).sum();
+            let total = members.len() as f64;
+            groups.push(HosmerLemeshowGroup { total, observed: [total - observed1, observed1], expected: [total - expected1, expected1] });
+            start = end;
+        }
+        groups
+    }
+
+    // Hosmer-Lemeshow chi-square with groups - 2 degrees of freedom
+    pub fn hosmer_lemeshow(&self) -> (f64, f64, f64) {
+        let groups = self.hosmer_lemeshow_groups();
+        let chi_square: f64 = groups
+            .iter()
+            .map(|g| (0..2).map(|c| if g.expected[c] > 0.0 { (g.observed[c] - g.expected[c]).powi(2) / g.expected[c] } else { 0.0 }).sum::<f64>())
+            .sum();
+        let df = groups.len() as f64 - 2.0;
+        (chi_square, df, chi_square_p_value(chi_square, df))
+    }
+}
+
+#[wasm_bindgen]
+impl LogisticRegression {
+    // Hosmer and Lemeshow Test with its Contingency Table
+    pub fn calculate_hosmer_lemeshow(&self) -> JsValue {
+        let (chi_square, df, p_value) = self.hosmer_lemeshow();
+        let table = Array::new();
+        for (g, group) in self.hosmer_lemeshow_groups().iter().enumerate() {
+            let row = Object::new();
+            Reflect::set(&row, &"Group".into(), &((g + 1) as f64).into()).unwrap();
+            Reflect::set(&row, &"Observed 0".into(), &group.observed[0].into()).unwrap();
+            Reflect::set(&row, &"Expected 0".into(), &group.expected[0].into()).unwrap();
+            Reflect::set(&row, &"Observed 1".into(), &group.observed[1].into()).unwrap();
+            Reflect::set(&row, &"Expected 1".into(), &group.expected[1].into()).unwrap();
+            Reflect::set(&row, &"Total".into(), &group.total.into()).unwrap();
+            table.push(&row);
+        }
+        let results = Object::new();
+        Reflect::set(&results, &"Chi-square".into(), &chi_square.into()).unwrap();
+        Reflect::set(&results, &"df".into(), &df.into()).unwrap();
+        Reflect::set(&results, &"Sig.".into(), &p_value.into()).unwrap();
+        Reflect::set(&results, &"Contingency Table".into(), &table).unwrap();
+        JsValue::from(results)
+    }
+
+    // Classification Table at the cut-off: counts of observed 0 and 1 predicted as 0 and 1,
+    // the percentage correct of each and the overall percentage
+    pub fn calculate_classification_table(&self) -> JsValue {
+        let cutoff = self.get_cutoff();
+        let y = self.get_y();
+        let mut count = [[0.0; 2]; 2];
+        for (observed, p) in y.iter().zip(self.get_probability().iter()) {
+            count[*observed as usize][if *p >= cutoff { 1 } else { 0 }] += 1.0;
+        }
+        let rows = Array::new();
+        for (observed, predicted) in count.iter().enumerate() {
+            let row = Object::new();
+            Reflect::set(&row, &"Observed".into(), &(observed as f64).into()).unwrap();
+            Reflect::set(&row, &"Predicted 0".into(), &predicted[0].into()).unwrap();
+            Reflect::set(&row, &"Predicted 1".into(), &predicted[1].into()).unwrap();
+            Reflect::set(&row, &"Percentage Correct".into(), &(100.0 * predicted[observed] / (predicted[0] + predicted[1])).into()).unwrap();
+            rows.push(&row);
+        }
+        let overall = Object::new();
+        Reflect::set(&overall, &"Observed".into(), &"Overall Percentage".into()).unwrap();
+        Reflect::set(&overall, &"Percentage Correct".into(), &(100.0 * (count[0][0] + count[1][1]) / y.len() as f64).into()).unwrap();
+        rows.push(&overall);
+        JsValue::from(rows)
+    }
+}
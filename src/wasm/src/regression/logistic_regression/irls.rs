@@ -0,0 +1,144 @@
+use nalgebra::{DMatrix, DVector};
+use crate::regression::linear_model::ols::aliased_columns;
+
+pub const MAX_ITERATION: usize = 20;
+const TOLERANCE: f64 = 1e-8;
+
+// Maximum likelihood fit of a logistic model. Aliased design columns get a NaN coefficient
+// and NaN rows and columns in the covariance (X'WX)^-1
+pub struct LogisticFit {
+    pub coefficient: Vec<f64>,
+    pub aliased: Vec<bool>,
+    pub covariance: DMatrix<f64>,
+    pub probability: Vec<f64>,
+    pub log_likelihood: f64,
+    pub iteration: usize,
+    pub converged: bool,
+    pub separation: String,
+}
+
+impl LogisticFit {
+    pub fn standard_error(&self) -> Vec<f64> {
+        (0..self.coefficient.len()).map(|i| self.covariance[(i, i)].sqrt()).collect()
+    }
+
+    pub fn covariance_rows(&self) -> Vec<Vec<f64>> {
+        (0..self.covariance.nrows()).map(|i| self.covariance.row(i).iter().cloned().collect()).collect()
+    }
+}
+
+fn logistic(eta: f64) -> f64 {
+    if eta >= 0.0 { 1.0 / (1.0 + (-eta).exp()) } else { eta.exp() / (1.0 + eta.exp()) }
+}
+
+// Sum of y eta - ln(1 + e^eta), computed without overflow
+fn log_likelihood(eta: &DVector<f64>, y: &[f64]) -> f64 {
+    eta.iter().zip(y.iter()).map(|(e, y)| y * e - (e.max(0.0) + (-e.abs()).exp().ln_1p())).sum()
+}
+
+// Inverse of the information matrix, by the pseudo-inverse when it is numerically singular
+fn information_inverse(information: &DMatrix<f64>) -> DMatrix<f64> {
+    match information.clone().cholesky() {
+        Some(cholesky) => cholesky.inverse(),
+        None => information.clone().pseudo_inverse(1e-12).unwrap(),
+    }
+}
+
+// Information matrix X'WX with w = p(1 - p)
+pub fn information(x: &DMatrix<f64>, probability: &[f64]) -> DMatrix<f64> {
+    let weighted = DMatrix::from_fn(x.nrows(), x.ncols(), |i, j| x[(i, j)] * probability[i] * (1.0 - probability[i]));
+    x.transpose() * weighted
+}
+
+// Newton-Raphson (iteratively reweighted least squares) from the constant-only solution,
+// halving the step while the log-likelihood decreases
+pub fn fit_logistic(x: &DMatrix<f64>, y: &[f64]) -> LogisticFit {
+    let (n, k) = (x.nrows(), x.ncols());
+    let aliased = aliased_columns(x);
+    let kept: Vec<usize> = (0..k).filter(|j| !aliased[*j]).collect();
+    let design = x.select_columns(kept.iter());
+    let p = kept.len();
+
+    let mean = y.iter().sum::<f64>() / n as f64;
+    let mut beta = DVector::zeros(p);
+    if kept.first() == Some(&0) && x.column(0).iter().all(|v| *v == 1.0) {
+        beta[0] = (mean / (1.0 - mean)).ln();
+    }
+    let y_vector = DVector::from_column_slice(y);
+    let mut eta = &design * &beta;
+    let mut current = log_likelihood(&eta, y);
+    let (mut iteration, mut converged) = (0, false);
+    while iteration < MAX_ITERATION {
+        iteration += 1;
+        let probability: Vec<f64> = eta.iter().map(|e| logistic(*e)).collect();
+        let score = design.transpose() * (&y_vector - DVector::from_vec(probability.clone()));
+        let step = information_inverse(&information(&design, &probability)) * score;
+        let mut scale = 1.0;
+        let (mut next_beta, mut next_eta, mut next) = (beta.clone(), eta.clone(), current);
+        for _ in 0..20 {
+            next_beta = &beta + &step * scale;
+            next_eta = &design * &next_beta;
+            next = log_likelihood(&next_eta, y);
+            if next >= current - TOLERANCE * current.abs() {
+                break;
+            }
+            scale /= 2.0;
+        }
+        let change = (next - current).abs();
+        let largest_step = (&next_beta - &beta).amax();
+        beta = next_beta;
+        eta = next_eta;
+        current = next;
+        if change <= TOLERANCE * (current.abs() + TOLERANCE) && largest_step <= 1e-6 * (beta.amax() + 1.0) {
+            converged = true;
+            break;
+        }
+    }
+
+    let probability: Vec<f64> = eta.iter().map(|e| logistic(*e)).collect();
+    let inverse = information_inverse(&information(&design, &probability));
+    let mut coefficient = vec![f64::NAN; k];
+    let mut covariance = DMatrix::from_element(k, k, f64::NAN);
+    for (a, i) in kept.iter().enumerate() {
+        coefficient[*i] = beta[a];
+        for (b, j) in kept.iter().enumerate() {
+            covariance[(*i, *j)] = inverse[(a, b)];
+        }
+    }
+
+    // Divergent estimates with every case on its side of 0.5 (complete) or some cases fitted
+    // with probability 0 or 1 (quasi-complete)
+    let largest_se = (0..p).map(|a| inverse[(a, a)].sqrt()).fold(0.0, f64::max);
+    let diverged = !converged || largest_se > 1e3;
+    let separated = probability.iter().zip(y.iter()).all(|(p, y)| (*p > 0.5) == (*y == 1.0) && *p != 0.5);
+    let perfect = probability.iter().zip(y.iter()).any(|(p, y)| (*p - *y).abs() < 1e-6);
+    let separation = if diverged && separated {
+        "complete"
+    } else if diverged && perfect {
+        "quasi-complete"
+    } else {
+        "none"
+    };
+    LogisticFit {
+        coefficient,
+        aliased,
+        covariance,
+        probability,
+        log_likelihood: current,
+        iteration,
+        converged,
+        separation: separation.to_string(),
+    }
+}
+
+// Score statistic of the columns candidate added to a design, at the probabilities fitted
+// without them: U' I^-1 U with U = X'(y - p) zero for the columns already in the model
+pub fn score_statistic(x: &DMatrix<f64>, y: &[f64], probability: &[f64]) -> (f64, usize) {
+    let aliased = aliased_columns(x);
+    let kept: Vec<usize> = (0..x.ncols()).filter(|j| !aliased[*j]).collect();
+    let design = x.select_columns(kept.iter());
+    let residual = DVector::from_iterator(y.len(), y.iter().zip(probability.iter()).map(|(y, p)| y - p));
+    let score = design.transpose() * residual;
+    let statistic = (score.transpose() * information_inverse(&information(&design, probability)) * &score)[(0, 0)];
+    (statistic, kept.len())
+}
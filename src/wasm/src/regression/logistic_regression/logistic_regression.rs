@@ -0,0 +1,180 @@
+use wasm_bindgen::prelude::*;
+use serde_wasm_bindgen::{from_value, to_value};
+
+#[wasm_bindgen]
+pub struct LogisticRegression {
+    x: Vec<Vec<f64>>,            // Covariates, one column each
+    x_header: Vec<String>,
+    y: Vec<f64>,                 // Dependent coded 0 and 1, the larger of its two values as 1
+    coding: Vec<String>,         // Per covariate: "none" (continuous), "indicator", "deviation" or "simple"
+    reference: Vec<String>,      // Reference category of a categorical covariate: "first" or "last"
+    cutoff: f64,                 // Classification cut-off of the predicted probability
+    selected: Vec<usize>,        // Covariates in the fitted model
+    parameter_name: Vec<String>, // Names of the design columns, the constant first
+    coefficient: Vec<f64>,       // NaN for aliased design columns
+    covariance: Vec<Vec<f64>>,
+    probability: Vec<f64>,       // Predicted probability of each case
+    log_likelihood: f64,
+    null_log_likelihood: f64,    // Log-likelihood of the constant-only model
+    iteration: usize,
+    converged: bool,
+    separation: String,          // "none", "quasi-complete" or "complete"
+}
+
+#[wasm_bindgen]
+impl LogisticRegression {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: JsValue, x_header: Vec<String>, y: Vec<f64>) -> LogisticRegression {
+        let x: Vec<Vec<f64>> = from_value(x).unwrap();
+        LogisticRegression::from_columns(x, x_header, y)
+    }
+
+    // Getters
+    pub fn get_x(&self) -> JsValue {
+        to_value(&self.x).unwrap()
+    }
+    pub fn get_x_header(&self) -> Vec<String> {
+        self.x_header.clone()
+    }
+    pub fn get_y(&self) -> Vec<f64> {
+        self.y.clone()
+    }
+    pub fn get_coding(&self) -> Vec<String> {
+        self.coding.clone()
+    }
+    pub fn get_reference(&self) -> Vec<String> {
+        self.reference.clone()
+    }
+    pub fn get_cutoff(&self) -> f64 {
+        self.cutoff
+    }
+    pub fn get_selected(&self) -> Vec<usize> {
+        self.selected.clone()
+    }
+    pub fn get_parameter_name(&self) -> Vec<String> {
+        self.parameter_name.clone()
+    }
+    pub fn get_coefficient(&self) -> Vec<f64> {
+        self.coefficient.clone()
+    }
+    pub fn get_covariance(&self) -> JsValue {
+        to_value(&self.covariance).unwrap()
+    }
+    pub fn get_probability(&self) -> Vec<f64> {
+        self.probability.clone()
+    }
+    pub fn get_log_likelihood(&self) -> f64 {
+        self.log_likelihood
+    }
+    pub fn get_null_log_likelihood(&self) -> f64 {
+        self.null_log_likelihood
+    }
+    pub fn get_iteration(&self) -> usize {
+        self.iteration
+    }
+    pub fn get_converged(&self) -> bool {
+        self.converged
+    }
+    pub fn get_separation(&self) -> String {
+        self.separation.clone()
+    }
+
+    // Setters
+    pub fn set_cutoff(&mut self, cutoff: f64) {
+        if cutoff <= 0.0 || cutoff >= 1.0 {
+            panic!("Classification cut-off must be between 0 and 1");
+        }
+        self.cutoff = cutoff;
+    }
+    // Declare covariate index categorical with its contrast coding and reference category
+    pub fn set_categorical(&mut self, index: usize, coding: String, reference: String) {
+        if index >= self.x.len() {
+            panic!("Covariate index out of range");
+        }
+        match coding.as_str() {
+            "none" | "indicator" | "deviation" | "simple" => {}
+            _ => panic!("Unknown contrast coding: {}", coding),
+        }
+        if reference != "first" && reference != "last" {
+            panic!("Unknown reference category: {}", reference);
+        }
+        self.coding[index] = coding;
+        self.reference[index] = reference;
+    }
+    pub fn set_selected(&mut self, selected: Vec<usize>) {
+        self.selected = selected;
+    }
+    pub fn set_parameter_name(&mut self, parameter_name: Vec<String>) {
+        self.parameter_name = parameter_name;
+    }
+    pub fn set_coefficient(&mut self, coefficient: Vec<f64>) {
+        self.coefficient = coefficient;
+    }
+    pub fn set_probability(&mut self, probability: Vec<f64>) {
+        self.probability = probability;
+    }
+    pub fn set_log_likelihood(&mut self, log_likelihood: f64) {
+        self.log_likelihood = log_likelihood;
+    }
+    pub fn set_iteration(&mut self, iteration: usize) {
+        self.iteration = iteration;
+    }
+    pub fn set_converged(&mut self, converged: bool) {
+        self.converged = converged;
+    }
+    pub fn set_separation(&mut self, separation: String) {
+        self.separation = separation;
+    }
+}
+
+impl LogisticRegression {
+    pub fn from_columns(x: Vec<Vec<f64>>, x_header: Vec<String>, y: Vec<f64>) -> LogisticRegression {
+        if x_header.len() != x.len() {
+            panic!("Every covariate needs a header");
+        }
+        if x.iter().any(|column| column.len() != y.len()) {
+            panic!("Covariates and dependent must have the same length");
+        }
+
+        // The two values of the dependent become 0 and 1
+        let mut value = y.clone();
+        value.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        value.dedup();
+        if value.len() != 2 {
+            panic!("Dependent variable must have exactly two values");
+        }
+        let y: Vec<f64> = y.iter().map(|v| if *v == value[1] { 1.0 } else { 0.0 }).collect();
+        let n1: f64 = y.iter().sum();
+        let n = y.len() as f64;
+        let null_log_likelihood = n1 * (n1 / n).ln() + (n - n1) * ((n - n1) / n).ln();
+        let k = x.len();
+        LogisticRegression {
+            x,
+            x_header,
+            y,
+            coding: vec!["none".to_string(); k],
+            reference: vec!["last".to_string(); k],
+            cutoff: 0.5,
+            selected: Vec::new(),
+            parameter_name: Vec::new(),
+            coefficient: Vec::new(),
+            covariance: Vec::new(),
+            probability: Vec::new(),
+            log_likelihood: f64::NAN,
+            null_log_likelihood,
+            iteration: 0,
+            converged: false,
+            separation: "none".to_string(),
+        }
+    }
+
+    pub fn get_x_values(&self) -> Vec<Vec<f64>> {
+        self.x.clone()
+    }
+    pub fn get_covariance_values(&self) -> Vec<Vec<f64>> {
+        self.covariance.clone()
+    }
+    pub fn set_covariance(&mut self, covariance: Vec<Vec<f64>>) {
+        self.covariance = covariance;
+    }
+}
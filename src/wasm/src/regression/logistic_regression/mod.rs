@@ -0,0 +1,6 @@
+pub mod logistic_regression;
+pub mod coding;
+pub mod irls;
+pub mod calculate_logistic;
+pub mod goodness_of_fit;
+pub mod stepwise_logistic;
\ No newline at end of file
@@ -0,0 +1,155 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use crate::LogisticRegression;
+use crate::regression::logistic_regression::calculate_logistic::{chi_square_p_value, wald_statistic};
+use crate::regression::logistic_regression::irls::{score_statistic, LogisticFit};
+
+// One step of the variable selection: the covariate entered or removed, the covariates in
+// the model, its -2 log-likelihood and the model and step chi-squares
+pub struct LogisticStep {
+    pub entered: Option<usize>,
+    pub removed: Option<usize>,
+    pub selected: Vec<usize>,
+    pub minus_2ll: f64,
+    pub model_chi_square: f64,
+    pub model_df: f64,
+    pub step_chi_square: f64,
+    pub step_df: f64,
+    pub p_value: f64,
+}
+
+fn rank(fit: &LogisticFit) -> usize {
+    fit.aliased.iter().filter(|a| !**a).count()
+}
+
+impl LogisticRegression {
+    // Covariate with the largest score statistic per degree of freedom, if its probability is at most entry
+    fn entry_candidate(&self, selected: &[usize], fit: &LogisticFit, entry: f64) -> Option<usize> {
+        let mut best: Option<(usize, f64, usize)> = None;
+        for j in (0..self.get_x_values().len()).filter(|j| !selected.contains(j)) {
+            let mut extended = selected.to_vec();
+            extended.push(j);
+            let (design, _, _) = self.design(&extended);
+            let (statistic, extended_rank) = score_statistic(&design, &self.get_y(), &fit.probability);
+            let df = extended_rank.saturating_sub(rank(fit));
+            if df == 0 {
+                continue;
+            }
+            if best.is_none_or(|(_, value, best_df)| statistic / df as f64 > value / best_df as f64) {
+                best = Some((j, statistic, df));
+            }
+        }
+        best.filter(|(_, statistic, df)| chi_square_p_value(*statistic, *df as f64) <= entry).map(|(j, _, _)| j)
+    }
+
+    // Covariate with the smallest likelihood-ratio or Wald statistic per degree of freedom, if its
+    // probability is at least removal. An aliased covariate adds nothing and is removed first
+    fn removal_candidate(&self, selected: &[usize], fit: &LogisticFit, statistic: &str, removal: f64) -> Option<usize> {
+        let (_, _, owner) = self.design(selected);
+        let per_df = |value: f64, df: usize| if df == 0 { f64::NEG_INFINITY } else { value / df as f64 };
+        let mut worst: Option<(usize, f64, usize)> = None;
+        for j in selected.iter() {
+            let (value, df) = if statistic == "lr" {
+                let reduced: Vec<usize> = selected.iter().filter(|i| *i != j).cloned().collect();
+                let reduced_fit = self.fit(&reduced);
+                (2.0 * (fit.log_likelihood - reduced_fit.log_likelihood), rank(fit) - rank(&reduced_fit))
+            } else {
+                let columns: Vec<usize> = (0..owner.len()).filter(|c| owner[*c] == Some(*j)).collect();
+                wald_statistic(fit, &columns)
+            };
+            if worst.is_none_or(|(_, worst_value, worst_df)| per_df(value, df) < per_df(worst_value, worst_df)) {
+                worst = Some((*j, value, df));
+            }
+        }
+        worst.filter(|(_, value, df)| *df == 0 || chi_square_p_value(*value, *df as f64) >= removal).map(|(j, _, _)| j)
+    }
+
+    fn logistic_step(&self, entered: Option<usize>, removed: Option<usize>, selected: &[usize], fit: &LogisticFit, previous: Option<(f64, usize)>) -> LogisticStep {
+        let null = self.get_null_log_likelihood();
+        let (previous_ll, previous_rank) = previous.unwrap_or((null, 1));
+        let step_df = rank(fit) as f64 - previous_rank as f64;
+        let step_chi_square = 2.0 * (fit.log_likelihood - previous_ll);
+        LogisticStep {
+            entered,
+            removed,
+            selected: selected.to_vec(),
+            minus_2ll: -2.0 * fit.log_likelihood,
+            model_chi_square: 2.0 * (fit.log_likelihood - null),
+            model_df: rank(fit) as f64 - 1.0,
+            step_chi_square,
+            step_df,
+            p_value: chi_square_p_value(step_chi_square.abs(), step_df.abs()),
+        }
+    }
+
+    // Selection by method "enter", "forward_lr", "forward_wald", "backward_lr" or "backward_wald".
+    // Covariates enter by the score test with probability at most entry and leave by the
+    // likelihood-ratio or Wald test with probability at least removal, a categorical covariate
+    // as one block. The final model is stored
+    pub fn logistic_stepwise(&mut self, method: &str, entry: f64, removal: f64) -> Vec<LogisticStep> {
+        let k = self.get_x_values().len();
+        let (forward, statistic) = match method {
+            "enter" => (false, ""),
+            "forward_lr" => (true, "lr"),
+            "forward_wald" => (true, "wald"),
+            "backward_lr" => (false, "lr"),
+            "backward_wald" => (false, "wald"),
+            _ => panic!("Unknown selection method: {}", method),
+        };
+        if method != "enter" && entry >= removal {
+            panic!("Entry probability must be less than removal probability");
+        }
+        let mut selected: Vec<usize> = if forward { Vec::new() } else { (0..k).collect() };
+        let mut fit = self.fit(&selected);
+        let mut steps = Vec::new();
+        if !forward {
+            steps.push(self.logistic_step(None, None, &selected, &fit, None));
+        }
+        if method != "enter" {
+            // Removal is checked before each entry; the step limit guards against cycling
+            for _ in 0..2 * k + 2 {
+                let previous = Some((fit.log_likelihood, rank(&fit)));
+                let (entered, removed) = if let Some(j) = self.removal_candidate(&selected, &fit, statistic, removal) {
+                    selected.retain(|i| *i != j);
+                    (None, Some(j))
+                } else if let Some(j) = self.entry_candidate(&selected, &fit, entry) {
+                    selected.push(j);
+                    (Some(j), None)
+                } else {
+                    break;
+                };
+                fit = self.fit(&selected);
+                steps.push(self.logistic_step(entered, removed, &selected, &fit, previous));
+            }
+        }
+        self.store_fit(selected);
+        steps
+    }
+}
+
+#[wasm_bindgen]
+impl LogisticRegression {
+    // Fit the model by the selection method with the entry and removal probabilities (SPSS
+    // uses 0.05 and 0.10) and return the step history; covariates are named by their headers
+    pub fn calculate_logistic_regression(&mut self, method: String, entry: f64, removal: f64) -> JsValue {
+        let header = self.get_x_header();
+        let name = |j: Option<usize>| j.map_or(JsValue::NULL, |j| header[j].clone().into());
+        let rows = Array::new();
+        for (i, step) in self.logistic_stepwise(&method, entry, removal).iter().enumerate() {
+            let in_model: Vec<String> = step.selected.iter().map(|j| header[*j].clone()).collect();
+            let row = Object::new();
+            Reflect::set(&row, &"Step".into(), &((i + 1) as f64).into()).unwrap();
+            Reflect::set(&row, &"Entered".into(), &name(step.entered)).unwrap();
+            Reflect::set(&row, &"Removed".into(), &name(step.removed)).unwrap();
+            Reflect::set(&row, &"Variables in Model".into(), &serde_wasm_bindgen::to_value(&in_model).unwrap()).unwrap();
+            Reflect::set(&row, &"-2 Log likelihood".into(), &step.minus_2ll.into()).unwrap();
+            Reflect::set(&row, &"Model Chi-square".into(), &step.model_chi_square.into()).unwrap();
+            Reflect::set(&row, &"Model df".into(), &step.model_df.into()).unwrap();
+            Reflect::set(&row, &"Step Chi-square".into(), &step.step_chi_square.into()).unwrap();
+            Reflect::set(&row, &"Step df".into(), &step.step_df.into()).unwrap();
+            Reflect::set(&row, &"Sig.".into(), &step.p_value.into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
@@ -2,4 +2,7 @@ pub mod simple_linear_regression;
 pub mod no_intercept_linear_regression;
 pub mod multiple_linear_regression;
 pub mod simple_exponential_regression;
-pub mod quadratic_regression;
\ No newline at end of file
+pub mod quadratic_regression;
+pub mod linear_model;
+pub mod weight_estimation;
+pub mod logistic_regression;
\ No newline at end of file
@@ -0,0 +1,75 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{ContinuousCDF, FisherSnedecor};
+use crate::MultipleLinearRegression;
+
+// Upper tail probability of an F statistic
+pub fn f_p_value(f: f64, df1: f64, df2: f64) -> f64 {
+    if df1 <= 0.0 || df2 <= 0.0 || !f.is_finite() {
+        return f64::NAN;
+    }
+    1.0 - FisherSnedecor::new(df1, df2).unwrap().cdf(f.max(0.0))
+}
+
+impl MultipleLinearRegression {
+    // Regression, residual and total sums of squares with their degrees of freedom
+    pub fn anova_components(&self) -> ([f64; 3], [f64; 3]) {
+        let sse = self.calculate_sse();
+        let sst = self.calculate_sst();
+        let n = self.get_y().len() as f64;
+        let rank = self.get_rank() as f64;
+        ([sst - sse, sse, sst], [rank - 1.0, n - rank, n - 1.0])
+    }
+
+    // Overall F test of the predictors: (F, df1, df2, p-value)
+    pub fn f_test(&self) -> (f64, f64, f64, f64) {
+        let (ss, df) = self.anova_components();
+        let f = (ss[0] / df[0]) / (ss[1] / df[1]);
+        (f, df[0], df[1], f_p_value(f, df[0], df[1]))
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    // Overall F statistic of the regression
+    pub fn calculate_f_stat(&self) -> f64 {
+        self.f_test().0
+    }
+
+    pub fn calculate_f_pvalue(&self) -> f64 {
+        self.f_test().3
+    }
+
+    // Model Summary: R, R Square, Adjusted R Square and Std. Error of the Estimate
+    pub fn calculate_model_summary(&self) -> JsValue {
+        let r2 = self.calculate_r2();
+        let results = Object::new();
+        Reflect::set(&results, &"R".into(), &r2.max(0.0).sqrt().into()).unwrap();
+        Reflect::set(&results, &"R Square".into(), &r2.into()).unwrap();
+        Reflect::set(&results, &"Adjusted R Square".into(), &self.calculate_r2_adj().into()).unwrap();
+        Reflect::set(&results, &"Std. Error of the Estimate".into(), &self.calculate_mse().sqrt().into()).unwrap();
+        JsValue::from(results)
+    }
+
+    // ANOVA table with Regression, Residual and Total rows
+    pub fn calculate_anova(&self) -> JsValue {
+        let (ss, df) = self.anova_components();
+        let (f, _, _, p_value) = self.f_test();
+        let rows = Array::new();
+        for (i, source) in ["Regression", "Residual", "Total"].iter().enumerate() {
+            let row = Object::new();
+            Reflect::set(&row, &"Source".into(), &(*source).into()).unwrap();
+            Reflect::set(&row, &"Sum of Squares".into(), &ss[i].into()).unwrap();
+            Reflect::set(&row, &"df".into(), &df[i].into()).unwrap();
+            if i < 2 {
+                Reflect::set(&row, &"Mean Square".into(), &(ss[i] / df[i]).into()).unwrap();
+            }
+            if i == 0 {
+                Reflect::set(&row, &"F".into(), &f.into()).unwrap();
+                Reflect::set(&row, &"Sig.".into(), &p_value.into()).unwrap();
+            }
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
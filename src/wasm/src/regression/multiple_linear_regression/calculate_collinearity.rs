@@ -0,0 +1,90 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use nalgebra::{DMatrix, SymmetricEigen};
+use crate::MultipleLinearRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::ols::fit_linear_model;
+
+// Eigenvalues of the scaled cross-product matrix in decreasing order with their condition
+// indices and the proportion of each coefficient variance (rows) due to each dimension (columns)
+pub struct CollinearityDiagnostics {
+    pub eigenvalue: Vec<f64>,
+    pub condition_index: Vec<f64>,
+    pub variance_proportion: Vec<Vec<f64>>,
+}
+
+impl MultipleLinearRegression {
+    // Tolerance 1 - R^2 of each predictor regressed on the other predictors, 1 for a single predictor
+    pub fn tolerance(&self) -> Vec<f64> {
+        let x = self.get_x_values();
+        if x.len() == 1 {
+            return vec![1.0];
+        }
+        (0..x.len())
+            .map(|j| {
+                let others: Vec<Vec<f64>> = x.iter().enumerate().filter(|(i, _)| *i != j).map(|(_, c)| c.clone()).collect();
+                let model = fit_linear_model(&design_matrix(x[j].len(), &others, true), &x[j]);
+                let mean = x[j].iter().sum::<f64>() / x[j].len() as f64;
+                let sst: f64 = x[j].iter().map(|v| (v - mean).powi(2)).sum();
+                model.sse / sst
+            })
+            .collect()
+    }
+
+    // Belsley-Kuh-Welsch diagnostics of the design with the constant, columns scaled to unit length
+    pub fn collinearity_diagnostics(&self) -> CollinearityDiagnostics {
+        let design = design_matrix(self.get_y().len(), &self.get_x_values(), true);
+        let k = design.ncols();
+        let mut scaled = design.clone();
+        for j in 0..k {
+            let norm = design.column(j).norm();
+            if norm > 0.0 {
+                scaled.column_mut(j).scale_mut(1.0 / norm);
+            }
+        }
+        let eigen = SymmetricEigen::new(scaled.transpose() * &scaled);
+        let mut order: Vec<usize> = (0..k).collect();
+        order.sort_by(|a, b| eigen.eigenvalues[*b].partial_cmp(&eigen.eigenvalues[*a]).unwrap());
+        let eigenvalue: Vec<f64> = order.iter().map(|d| eigen.eigenvalues[*d].max(0.0)).collect();
+        let condition_index = eigenvalue.iter().map(|l| (eigenvalue[0] / l).sqrt()).collect();
+
+        // Share of var(b_j) = sum_d v_jd^2 / lambda_d contributed by each dimension d
+        let phi = DMatrix::from_fn(k, k, |j, d| eigen.eigenvectors[(j, order[d])].powi(2) / eigenvalue[d]);
+        let variance_proportion = (0..k)
+            .map(|j| {
+                let total: f64 = phi.row(j).sum();
+                phi.row(j).iter().map(|v| v / total).collect()
+            })
+            .collect();
+        CollinearityDiagnostics { eigenvalue, condition_index, variance_proportion }
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    pub fn calculate_tolerance(&self) -> Vec<f64> {
+        self.tolerance()
+    }
+
+    // Variance inflation factor 1 / tolerance of each predictor
+    pub fn calculate_vif(&self) -> Vec<f64> {
+        self.tolerance().iter().map(|t| 1.0 / t).collect()
+    }
+
+    // Collinearity Diagnostics table, one row per dimension with the variance proportions
+    // of the constant and the predictors in design order
+    pub fn calculate_collinearity_diagnostics(&self) -> JsValue {
+        let diagnostics = self.collinearity_diagnostics();
+        let rows = Array::new();
+        for (d, eigenvalue) in diagnostics.eigenvalue.iter().enumerate() {
+            let proportion: Vec<f64> = diagnostics.variance_proportion.iter().map(|p| p[d]).collect();
+            let row = Object::new();
+            Reflect::set(&row, &"Dimension".into(), &((d + 1) as f64).into()).unwrap();
+            Reflect::set(&row, &"Eigenvalue".into(), &(*eigenvalue).into()).unwrap();
+            Reflect::set(&row, &"Condition Index".into(), &diagnostics.condition_index[d].into()).unwrap();
+            Reflect::set(&row, &"Variance Proportions".into(), &serde_wasm_bindgen::to_value(&proportion).unwrap()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
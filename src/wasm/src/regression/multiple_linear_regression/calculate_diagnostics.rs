@@ -0,0 +1,140 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use crate::MultipleLinearRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::interval::LinearDesign;
+
+// Case-wise regression diagnostics, one value per case (DFBETA and DFBETAS one column per coefficient)
+pub struct CaseDiagnostics {
+    pub predicted: Vec<f64>,
+    pub residual: Vec<f64>,
+    pub standardized_residual: Vec<f64>,
+    pub studentized_residual: Vec<f64>,
+    pub deleted_residual: Vec<f64>,
+    pub studentized_deleted_residual: Vec<f64>,
+    pub leverage: Vec<f64>,          // Hat values h_i
+    pub centered_leverage: Vec<f64>, // h_i - 1/n as reported by SPSS
+    pub mahalanobis: Vec<f64>,
+    pub cooks_distance: Vec<f64>,
+    pub dffit: Vec<f64>,
+    pub dffits: Vec<f64>,
+    pub dfbeta: Vec<Vec<f64>>,
+    pub dfbetas: Vec<Vec<f64>>,
+    pub covratio: Vec<f64>,
+}
+
+impl MultipleLinearRegression {
+    pub fn case_diagnostics(&self) -> CaseDiagnostics {
+        let model = self.linear_model();
+        let design = design_matrix(self.get_y().len(), &self.get_x_values(), true);
+        let (n, k) = (design.nrows(), design.ncols());
+        let p = model.rank as f64;
+        let mse = model.mse();
+        let s = mse.sqrt();
+        let kept: Vec<usize> = (0..k).filter(|j| !model.aliased[*j]).collect();
+        let covariance = &model.unscaled_covariance;
+
+        // (X'X)^-1 x_i of every case, zero for the aliased columns
+        let weighted: Vec<Vec<f64>> = (0..n)
+            .map(|i| {
+                (0..k)
+                    .map(|a| if model.aliased[a] { 0.0 } else { kept.iter().map(|b| covariance[(a, *b)] * design[(i, *b)]).sum() })
+                    .collect()
+            })
+            .collect();
+        let leverage: Vec<f64> = (0..n).map(|i| kept.iter().map(|j| design[(i, *j)] * weighted[i][*j]).sum()).collect();
+
+        let mut result = CaseDiagnostics {
+            predicted: model.fitted.clone(),
+            residual: model.residual.clone(),
+            standardized_residual: Vec::with_capacity(n),
+            studentized_residual: Vec::with_capacity(n),
+            deleted_residual: Vec::with_capacity(n),
+            studentized_deleted_residual: Vec::with_capacity(n),
+            centered_leverage: leverage.iter().map(|h| h - 1.0 / n as f64).collect(),
+            mahalanobis: leverage.iter().map(|h| (n as f64 - 1.0) * (h - 1.0 / n as f64)).collect(),
+            leverage: leverage.clone(),
+            cooks_distance: Vec::with_capacity(n),
+            dffit: Vec::with_capacity(n),
+            dffits: Vec::with_capacity(n),
+            dfbeta: vec![Vec::with_capacity(n); k],
+            dfbetas: vec![Vec::with_capacity(n); k],
+            covratio: Vec::with_capacity(n),
+        };
+        for i in 0..n {
+            let (e, h) = (model.residual[i], leverage[i]);
+
+            // Residual variance with case i deleted
+            let deleted_mse = (model.sse - e * e / (1.0 - h)) / (model.df_residual - 1.0);
+            let deleted_s = deleted_mse.sqrt();
+            let studentized_deleted = e / (deleted_s * (1.0 - h).sqrt());
+            result.standardized_residual.push(e / s);
+            result.studentized_residual.push(e / (s * (1.0 - h).sqrt()));
+            result.deleted_residual.push(e / (1.0 - h));
+            result.studentized_deleted_residual.push(studentized_deleted);
+            result.cooks_distance.push(e * e * h / (p * mse * (1.0 - h).powi(2)));
+            result.dffit.push(e * h / (1.0 - h));
+            result.dffits.push(studentized_deleted * (h / (1.0 - h)).sqrt());
+            result.covratio.push((deleted_mse / mse).powf(p) / (1.0 - h));
+
+            // Change in each coefficient when case i is deleted, scaled by its deleted standard error
+            for j in 0..k {
+                let change = if model.aliased[j] { f64::NAN } else { weighted[i][j] * e / (1.0 - h) };
+                result.dfbeta[j].push(change);
+                result.dfbetas[j].push(change / (deleted_s * covariance[(j, j)].sqrt()));
+            }
+        }
+        result
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    // Case-wise diagnostics keyed by the names of the SPSS Save options; DFBETA and DFBETAS
+    // hold one array per coefficient, the constant first
+    pub fn calculate_case_diagnostics(&self) -> JsValue {
+        let diagnostics = self.case_diagnostics();
+        let results = Object::new();
+        let set = |key: &str, value: JsValue| {
+            Reflect::set(&results, &key.into(), &value).unwrap();
+        };
+        let values = |v: &Vec<f64>| serde_wasm_bindgen::to_value(v).unwrap();
+        set("Unstandardized Predicted Value", values(&diagnostics.predicted));
+        set("Unstandardized Residual", values(&diagnostics.residual));
+        set("Standardized Residual", values(&diagnostics.standardized_residual));
+        set("Studentized Residual", values(&diagnostics.studentized_residual));
+        set("Deleted Residual", values(&diagnostics.deleted_residual));
+        set("Studentized Deleted Residual", values(&diagnostics.studentized_deleted_residual));
+        set("Leverage", values(&diagnostics.leverage));
+        set("Centered Leverage Value", values(&diagnostics.centered_leverage));
+        set("Mahalanobis Distance", values(&diagnostics.mahalanobis));
+        set("Cook's Distance", values(&diagnostics.cooks_distance));
+        set("DfFit", values(&diagnostics.dffit));
+        set("Standardized DfFit", values(&diagnostics.dffits));
+        set("DfBeta", serde_wasm_bindgen::to_value(&diagnostics.dfbeta).unwrap());
+        set("Standardized DfBeta", serde_wasm_bindgen::to_value(&diagnostics.dfbetas).unwrap());
+        set("Covariance Ratio", values(&diagnostics.covratio));
+        JsValue::from(results)
+    }
+
+    // Casewise Diagnostics table of the cases whose standardized residual is at least
+    // threshold in absolute value (SPSS uses 3), case numbers counted from 1
+    pub fn calculate_casewise_diagnostics(&self, threshold: f64) -> JsValue {
+        let diagnostics = self.case_diagnostics();
+        let y = self.get_y();
+        let rows = Array::new();
+        for (i, z) in diagnostics.standardized_residual.iter().enumerate() {
+            if z.abs() < threshold {
+                continue;
+            }
+            let row = Object::new();
+            Reflect::set(&row, &"Case Number".into(), &((i + 1) as f64).into()).unwrap();
+            Reflect::set(&row, &"Std. Residual".into(), &(*z).into()).unwrap();
+            Reflect::set(&row, &"Dependent".into(), &y[i].into()).unwrap();
+            Reflect::set(&row, &"Predicted Value".into(), &diagnostics.predicted[i].into()).unwrap();
+            Reflect::set(&row, &"Residual".into(), &diagnostics.residual[i].into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
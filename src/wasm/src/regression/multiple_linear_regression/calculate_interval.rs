@@ -0,0 +1,45 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use serde_wasm_bindgen::from_value;
+use crate::MultipleLinearRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::interval::{interval_js, LinearDesign};
+
+impl LinearDesign for MultipleLinearRegression {
+    type Predictors = Vec<Vec<f64>>;
+
+    fn predictors(&self) -> Vec<Vec<f64>> {
+        self.get_x_values()
+    }
+
+    fn response(&self) -> Vec<f64> {
+        self.get_y()
+    }
+
+    // Design matrix of the constant and one column per predictor
+    fn design(x: &Vec<Vec<f64>>) -> DMatrix<f64> {
+        design_matrix(x.first().map_or(0, |c| c.len()), x, true)
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    // Confidence intervals of the coefficients at the given level, e.g. 0.95, from the same
+    // covariance (covariance_type) and degrees of freedom as the t tests
+    pub fn calculate_confidence_interval(&self, level: f64) -> JsValue {
+        let (lower, upper) = self.linear_model().coefficient_interval_from(&self.calculate_standard_error(), self.inference_df(), level);
+        interval_js(&lower, &upper)
+    }
+
+    // Predicted values of new cases, new_x holds one column per predictor like x
+    pub fn predict(&self, new_x: JsValue) -> Vec<f64> {
+        let new_x: Vec<Vec<f64>> = from_value(new_x).unwrap();
+        self.predict_values(&new_x)
+    }
+
+    // Confidence interval of the mean response and prediction interval of new cases
+    pub fn calculate_prediction_interval(&self, new_x: JsValue, level: f64) -> JsValue {
+        let new_x: Vec<Vec<f64>> = from_value(new_x).unwrap();
+        self.prediction_interval_js(&new_x, level)
+    }
+}
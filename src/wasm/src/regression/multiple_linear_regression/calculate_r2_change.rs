@@ -0,0 +1,76 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use crate::MultipleLinearRegression;
+use crate::regression::multiple_linear_regression::calculate_anova::f_p_value;
+
+// Change statistics of one block: R Square Change, F Change, df1, df2 and Sig. F Change
+pub struct R2Change {
+    pub r2: f64,
+    pub r2_adj: f64,
+    pub se_estimate: f64,
+    pub r2_change: f64,
+    pub f_change: f64,
+    pub df1: f64,
+    pub df2: f64,
+    pub p_value: f64,
+}
+
+impl MultipleLinearRegression {
+    // Hierarchical regression: block_size[k] predictors (in the order of x) enter at step k,
+    // each model is compared with the one before it (the first with the constant only)
+    pub fn r2_change(&self, block_size: &[usize]) -> Vec<R2Change> {
+        let x = self.get_x_values();
+        if block_size.iter().sum::<usize>() > x.len() {
+            panic!("Blocks contain more predictors than the regression");
+        }
+        let n = self.get_y().len() as f64;
+        let mut steps = Vec::new();
+        let (mut entered, mut previous_r2, mut previous_rank) = (0, 0.0, 1.0);
+        for size in block_size {
+            entered += size;
+            let mut model = MultipleLinearRegression::from_columns(x[..entered].to_vec(), self.get_y());
+            model.calculate_regression();
+            let r2 = model.calculate_r2();
+            let rank = model.get_rank() as f64;
+            let df1 = rank - previous_rank;
+            let df2 = n - rank;
+            let f_change = ((r2 - previous_r2) / df1) / ((1.0 - r2) / df2);
+            steps.push(R2Change {
+                r2,
+                r2_adj: model.calculate_r2_adj(),
+                se_estimate: model.calculate_mse().sqrt(),
+                r2_change: r2 - previous_r2,
+                f_change,
+                df1,
+                df2,
+                p_value: f_p_value(f_change, df1, df2),
+            });
+            previous_r2 = r2;
+            previous_rank = rank;
+        }
+        steps
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    // Model Summary of a hierarchical regression with change statistics, one row per block
+    pub fn calculate_r2_change(&self, block_size: Vec<usize>) -> JsValue {
+        let rows = Array::new();
+        for (i, step) in self.r2_change(&block_size).iter().enumerate() {
+            let row = Object::new();
+            Reflect::set(&row, &"Model".into(), &((i + 1) as f64).into()).unwrap();
+            Reflect::set(&row, &"R".into(), &step.r2.max(0.0).sqrt().into()).unwrap();
+            Reflect::set(&row, &"R Square".into(), &step.r2.into()).unwrap();
+            Reflect::set(&row, &"Adjusted R Square".into(), &step.r2_adj.into()).unwrap();
+            Reflect::set(&row, &"Std. Error of the Estimate".into(), &step.se_estimate.into()).unwrap();
+            Reflect::set(&row, &"R Square Change".into(), &step.r2_change.into()).unwrap();
+            Reflect::set(&row, &"F Change".into(), &step.f_change.into()).unwrap();
+            Reflect::set(&row, &"df1".into(), &step.df1.into()).unwrap();
+            Reflect::set(&row, &"df2".into(), &step.df2.into()).unwrap();
+            Reflect::set(&row, &"Sig. F Change".into(), &step.p_value.into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
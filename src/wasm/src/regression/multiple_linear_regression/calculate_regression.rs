@@ -1,39 +1,19 @@
 use wasm_bindgen::prelude::*;
-use crate::{transpose, multiply_matrix, invert_matrix, multiply_matrix_vector};
 use crate::MultipleLinearRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::ols::fit_linear_model;
+
 #[wasm_bindgen]
 impl MultipleLinearRegression{
-    // Calculate the multiple linear regression
+    // Calculate the multiple linear regression by QR decomposition. Predictors that are
+    // linear combinations of earlier ones are reported in aliased and get a NaN coefficient
     pub fn calculate_regression(&mut self) {
-        // Initialize the variables
-        let x_values: Vec<Vec<f64>> = serde_wasm_bindgen::from_value(self.get_x()).unwrap();
-        let y_values: Vec<f64> = self.get_y().clone();
-        let mut y_prediction: Vec<f64> = Vec::new();
-        let m: usize = x_values.len();
-        let n: usize = x_values[0].len();
-        let mut design_matrix: Vec<Vec<f64>> = Vec::new();
-        let mut first_column = Vec::new();
-        for _ in 0..n{
-            first_column.push(1.0);
-        }
-        design_matrix.push(first_column);
-        for i in 0..m{
-            design_matrix.push(x_values[i].clone());
-        }
-        
-        let xt: Vec<Vec<f64>> = transpose(&design_matrix);
-        let xtx: Vec<Vec<f64>> = multiply_matrix(&xt, &design_matrix);
-        let xtx_inv: Vec<Vec<f64>> = invert_matrix(&xtx).unwrap();
-        let xt_y: Vec<f64> = multiply_matrix_vector(&xt, &y_values);
-        let beta: Vec<f64> = multiply_matrix_vector(&xtx_inv, &xt_y);
-        for i in 0..n{
-            let mut y_pred: f64 = beta[0];
-            for j in 1..beta.len(){
-                y_pred += beta[j] * design_matrix[j][i];
-            }
-            y_prediction.push(y_pred);
-        }
-        self.set_y_prediction(y_prediction.clone());
-        self.set_beta(beta);
+        let design = design_matrix(self.get_y().len(), &self.get_x_values(), true);
+        let model = fit_linear_model(&design, &self.get_y());
+        self.set_y_prediction(model.fitted.clone());
+        self.set_aliased(model.aliased_index());
+        self.set_rank(model.rank);
+        self.set_unscaled_covariance(model.unscaled_covariance_rows());
+        self.set_beta(model.coefficient);
     }
-}
\ No newline at end of file
+}
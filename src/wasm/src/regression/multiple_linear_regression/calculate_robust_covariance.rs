@@ -0,0 +1,124 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::{DMatrix, RowDVector};
+use crate::MultipleLinearRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::interval::LinearDesign;
+
+impl MultipleLinearRegression {
+    // Sandwich covariance (X'X)^-1 meat (X'X)^-1 of the estimable coefficients for the
+    // selected covariance type, NaN rows and columns for aliased predictors
+    pub fn robust_covariance(&self) -> DMatrix<f64> {
+        let model = self.linear_model();
+        let design = design_matrix(self.get_y().len(), &self.get_x_values(), true);
+        let k = design.ncols();
+        let kept: Vec<usize> = (0..k).filter(|j| !model.aliased[*j]).collect();
+        let x = design.select_columns(kept.iter());
+        let bread = model.unscaled_covariance.select_rows(kept.iter()).select_columns(kept.iter());
+        let (n, p) = (x.nrows(), x.ncols());
+        let e = &model.residual;
+
+        // Score x_i e_i of every case as the rows of a matrix
+        let score = |weight: &dyn Fn(usize) -> f64| DMatrix::from_fn(n, p, |i, j| x[(i, j)] * e[i] * weight(i));
+        let leverage: Vec<f64> = (0..n).map(|i| (x.row(i) * &bread * x.row(i).transpose())[(0, 0)]).collect();
+        let covariance_type = self.get_covariance_type();
+        let meat = match covariance_type.as_str() {
+            "hc0" | "hc1" => {
+                let u = score(&|_| 1.0);
+                let scale = if covariance_type == "hc1" { n as f64 / (n - p) as f64 } else { 1.0 };
+                u.transpose() * &u * scale
+            }
+            "hc2" => {
+                let u = score(&|i| (1.0 / (1.0 - leverage[i])).sqrt());
+                u.transpose() * &u
+            }
+            "hc3" => {
+                let u = score(&|i| 1.0 / (1.0 - leverage[i]));
+                u.transpose() * &u
+            }
+            "hc4" => {
+                // Discount exponent min(4, n h_i / p) of Cribari-Neto (2004)
+                let u = score(&|i| (1.0 - leverage[i]).powf(-(4.0f64).min(n as f64 * leverage[i] / p as f64) / 2.0));
+                u.transpose() * &u
+            }
+            "hac" => {
+                let u = score(&|_| 1.0);
+                let lag = newey_west_bandwidth(&u, &kept);
+                let mut meat = u.transpose() * &u;
+                for j in 1..=lag.min(n - 1) {
+                    let weight = 1.0 - j as f64 / (lag as f64 + 1.0);
+                    let gamma = u.rows(j, n - j).transpose() * u.rows(0, n - j);
+                    meat += (&gamma + gamma.transpose()) * weight;
+                }
+                meat
+            }
+            "cluster" => {
+                let cluster = self.get_cluster();
+                if cluster.len() != n {
+                    panic!("Cluster IDs must be given for every case");
+                }
+                let mut id: Vec<f64> = cluster.clone();
+                id.sort_by(|a, b| a.total_cmp(b));
+                id.dedup();
+                let g = id.len() as f64;
+                let u = score(&|_| 1.0);
+                let mut meat = DMatrix::zeros(p, p);
+                for value in id.iter() {
+                    let total = (0..n).filter(|i| cluster[*i] == *value).fold(RowDVector::zeros(p), |sum, i| sum + u.row(i));
+                    meat += total.transpose() * &total;
+                }
+                meat * (g / (g - 1.0) * (n as f64 - 1.0) / (n - p) as f64)
+            }
+            _ => panic!("Unknown covariance type: {}", covariance_type),
+        };
+        let sandwich = &bread * meat * &bread;
+        let mut covariance = DMatrix::from_element(k, k, f64::NAN);
+        for (a, i) in kept.iter().enumerate() {
+            for (b, j) in kept.iter().enumerate() {
+                covariance[(*i, *j)] = sandwich[(a, b)];
+            }
+        }
+        covariance
+    }
+
+    // Degrees of freedom of the t tests: the number of clusters minus one for cluster-robust
+    // standard errors, the residual degrees of freedom otherwise
+    pub fn inference_df(&self) -> f64 {
+        if self.get_covariance_type() == "cluster" {
+            let mut id = self.get_cluster();
+            id.sort_by(|a, b| a.total_cmp(b));
+            id.dedup();
+            id.len() as f64 - 1.0
+        } else {
+            self.get_y().len() as f64 - self.get_rank() as f64
+        }
+    }
+}
+
+// Newey-West (1994) automatic lag of the Bartlett kernel, from the autocovariances of the
+// scores summed over the slope coefficients
+fn newey_west_bandwidth(u: &DMatrix<f64>, kept: &[usize]) -> usize {
+    let n = u.nrows();
+    let f: Vec<f64> = (0..n).map(|i| (0..u.ncols()).filter(|j| kept[*j] != 0).map(|j| u[(i, j)]).sum()).collect();
+    let pilot = (4.0 * (n as f64 / 100.0).powf(2.0 / 9.0)).floor() as usize;
+    let sigma = |j: usize| (j..n).map(|t| f[t] * f[t - j]).sum::<f64>() / n as f64;
+    let s0 = sigma(0) + 2.0 * (1..=pilot.min(n - 1)).map(sigma).sum::<f64>();
+    let s1 = 2.0 * (1..=pilot.min(n - 1)).map(|j| j as f64 * sigma(j)).sum::<f64>();
+    if s0 <= 0.0 {
+        return 0;
+    }
+    let gamma = 1.1447 * ((s1 / s0).powi(2)).powf(1.0 / 3.0);
+    (gamma * (n as f64).powf(1.0 / 3.0)).floor() as usize
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    // Newey-West lag chosen for the HAC standard errors
+    pub fn calculate_hac_bandwidth(&self) -> usize {
+        let model = self.linear_model();
+        let design = design_matrix(self.get_y().len(), &self.get_x_values(), true);
+        let kept: Vec<usize> = (0..design.ncols()).filter(|j| !model.aliased[*j]).collect();
+        let x = design.select_columns(kept.iter());
+        let u = DMatrix::from_fn(x.nrows(), x.ncols(), |i, j| x[(i, j)] * model.residual[i]);
+        newey_west_bandwidth(&u, &kept)
+    }
+}
@@ -0,0 +1,261 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use nalgebra::{DMatrix, SymmetricEigen};
+use statrs::distribution::{ChiSquared, ContinuousCDF, Normal};
+use std::f64::consts::PI;
+use crate::MultipleLinearRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::ols::fit_linear_model;
+use crate::regression::linear_model::normality::{jarque_bera, shapiro_wilk};
+use crate::regression::multiple_linear_regression::calculate_anova::f_p_value;
+use crate::regression::linear_model::interval::LinearDesign;
+
+// Result of a residual test; df has one entry for chi-square and two for F statistics
+pub struct SpecificationTest {
+    pub test: String,
+    pub statistic: f64,
+    pub df: Vec<f64>,
+    pub p_value: f64,
+}
+
+impl SpecificationTest {
+    fn chi_square(test: &str, statistic: f64, df: f64) -> SpecificationTest {
+        let p_value = if df > 0.0 { 1.0 - ChiSquared::new(df).unwrap().cdf(statistic.max(0.0)) } else { f64::NAN };
+        SpecificationTest { test: test.to_string(), statistic, df: vec![df], p_value }
+    }
+
+    pub fn to_js(&self) -> JsValue {
+        let row = Object::new();
+        Reflect::set(&row, &"Test".into(), &self.test.clone().into()).unwrap();
+        Reflect::set(&row, &"Statistic".into(), &self.statistic.into()).unwrap();
+        if self.df.len() == 1 {
+            Reflect::set(&row, &"df".into(), &self.df[0].into()).unwrap();
+        } else {
+            for (i, df) in self.df.iter().enumerate() {
+                Reflect::set(&row, &format!("df{}", i + 1).into(), &(*df).into()).unwrap();
+            }
+        }
+        Reflect::set(&row, &"Sig.".into(), &self.p_value.into()).unwrap();
+        JsValue::from(row)
+    }
+}
+
+// Centered R^2 of an auxiliary regression
+fn auxiliary_r2(design: &DMatrix<f64>, y: &[f64]) -> (f64, usize) {
+    let model = fit_linear_model(design, y);
+    let mean = y.iter().sum::<f64>() / y.len() as f64;
+    let sst: f64 = y.iter().map(|v| (v - mean).powi(2)).sum();
+    (1.0 - model.sse / sst, model.rank)
+}
+
+// Design matrix with extra columns appended
+fn augment(design: &DMatrix<f64>, extra: &[Vec<f64>]) -> DMatrix<f64> {
+    let k = design.ncols();
+    DMatrix::from_fn(design.nrows(), k + extra.len(), |i, j| if j < k { design[(i, j)] } else { extra[j - k][i] })
+}
+
+// P(sum c_i z_i^2 < 0) for independent standard normal z by Imhof's (1961) inversion,
+// integrated by Simpson's rule after mapping u in [0, inf) to t in [0, 1)
+fn imhof_lower(c: &[f64]) -> f64 {
+    let scale = c.iter().fold(0.0f64, |m, v| m.max(v.abs()));
+    let c: Vec<f64> = c.iter().map(|v| v / scale).collect();
+    let integrand = |t: f64| {
+        if t == 0.0 {
+            return c.iter().sum::<f64>() / 2.0;
+        }
+        if t >= 1.0 {
+            return 0.0;
+        }
+        let u = t / (1.0 - t);
+        let theta: f64 = c.iter().map(|ci| (ci * u).atan()).sum::<f64>() / 2.0;
+        let log_rho: f64 = c.iter().map(|ci| (1.0 + (ci * u).powi(2)).ln()).sum::<f64>() / 4.0;
+        theta.sin() / (u * log_rho.exp()) / (1.0 - t).powi(2)
+    };
+    let steps = 4000;
+    let h = 1.0 / steps as f64;
+    let mut integral = integrand(0.0) + integrand(1.0);
+    for i in 1..steps {
+        integral += if i % 2 == 1 { 4.0 } else { 2.0 } * integrand(i as f64 * h);
+    }
+    (0.5 - integral * h / 3.0 / PI).clamp(0.0, 1.0)
+}
+
+impl MultipleLinearRegression {
+    fn design(&self) -> DMatrix<f64> {
+        design_matrix(self.get_y().len(), &self.get_x_values(), true)
+    }
+
+    // Durbin-Watson d with the p-value of positive autocorrelation P(D <= d). "exact" takes
+    // the distribution of d given the design from the eigenvalues of the residual space,
+    // "approximate" a normal distribution with the exact mean and variance of d
+    pub fn durbin_watson(&self, method: &str) -> SpecificationTest {
+        let model = self.linear_model();
+        let e = &model.residual;
+        let n = e.len();
+        let d = (1..n).map(|t| (e[t] - e[t - 1]).powi(2)).sum::<f64>() / model.sse;
+
+        // Durbin-Watson matrix A with d = e'Ae / e'e, and residual maker M
+        let a = DMatrix::from_fn(n, n, |i, j| {
+            if i == j {
+                if i == 0 || i == n - 1 { 1.0 } else { 2.0 }
+            } else if i.abs_diff(j) == 1 {
+                -1.0
+            } else {
+                0.0
+            }
+        });
+        let design = self.design();
+        let kept: Vec<usize> = (0..design.ncols()).filter(|j| !model.aliased[*j]).collect();
+        let q = design.select_columns(kept.iter()).qr().q();
+        let m = DMatrix::identity(n, n) - &q * q.transpose();
+        let df = n - model.rank;
+        let p_value = match method {
+            "exact" => {
+                // Orthonormal basis of the residual space and the eigenvalues of A on it
+                let eigen = SymmetricEigen::new(m.clone());
+                let basis: Vec<usize> = (0..n).filter(|i| eigen.eigenvalues[*i] > 0.5).collect();
+                let v = eigen.eigenvectors.select_columns(basis.iter());
+                let nu = SymmetricEigen::new(v.transpose() * &a * &v).eigenvalues;
+                let c: Vec<f64> = nu.iter().map(|l| l - d).collect();
+                imhof_lower(&c)
+            }
+            "approximate" => {
+                let ma = &m * &a;
+                let p = ma.trace();
+                let q2 = (&ma * &ma).trace();
+                let mean = p / df as f64;
+                let variance = 2.0 * (q2 - p * mean) / (df as f64 * (df as f64 + 2.0));
+                Normal::new(mean, variance.sqrt()).unwrap().cdf(d)
+            }
+            _ => panic!("Unknown Durbin-Watson method: {}", method),
+        };
+        SpecificationTest { test: "Durbin-Watson".to_string(), statistic: d, df: Vec::new(), p_value }
+    }
+
+    // Breusch-Godfrey LM test n R^2 of the residuals on the predictors and their own
+    // first order lags, the presample lags set to zero
+    pub fn breusch_godfrey(&self, order: usize) -> SpecificationTest {
+        let model = self.linear_model();
+        let e = &model.residual;
+        let n = e.len();
+        if order == 0 || order >= n {
+            panic!("Breusch-Godfrey order must be between 1 and the number of cases");
+        }
+        let lags: Vec<Vec<f64>> = (1..=order).map(|l| (0..n).map(|t| if t >= l { e[t - l] } else { 0.0 }).collect()).collect();
+        let (r2, _) = auxiliary_r2(&augment(&self.design(), &lags), e);
+        SpecificationTest::chi_square("Breusch-Godfrey", n as f64 * r2, order as f64)
+    }
+
+    // Breusch-Pagan test of the squared residuals on the predictors: half the explained sum of
+    // squares of e^2 / (SSE / n), or Koenker's studentized n R^2 when studentized is set
+    pub fn breusch_pagan(&self, studentized: bool) -> SpecificationTest {
+        let model = self.linear_model();
+        let n = model.residual.len() as f64;
+        let sigma2 = model.sse / n;
+        let g: Vec<f64> = model.residual.iter().map(|e| e * e / sigma2).collect();
+        let (r2, rank) = auxiliary_r2(&self.design(), &g);
+        let df = rank as f64 - 1.0;
+        if studentized {
+            SpecificationTest::chi_square("Koenker", n * r2, df)
+        } else {
+            let mean = g.iter().sum::<f64>() / n;
+            let sst: f64 = g.iter().map(|v| (v - mean).powi(2)).sum();
+            SpecificationTest::chi_square("Breusch-Pagan", r2 * sst / 2.0, df)
+        }
+    }
+
+    // White test n R^2 of the squared residuals on the predictors, their squares and cross
+    // products; duplicated columns (such as squares of dummies) are dropped as aliased
+    pub fn white(&self) -> SpecificationTest {
+        let model = self.linear_model();
+        let x = self.get_x_values();
+        let mut extra: Vec<Vec<f64>> = Vec::new();
+        for i in 0..x.len() {
+            for j in i..x.len() {
+                extra.push(x[i].iter().zip(x[j].iter()).map(|(a, b)| a * b).collect());
+            }
+        }
+        let e2: Vec<f64> = model.residual.iter().map(|e| e * e).collect();
+        let (r2, rank) = auxiliary_r2(&augment(&self.design(), &extra), &e2);
+        SpecificationTest::chi_square("White", e2.len() as f64 * r2, rank as f64 - 1.0)
+    }
+
+    // Ramsey RESET F test of the powers 2..=power of the fitted values added to the model
+    pub fn reset(&self, power: usize) -> SpecificationTest {
+        if power < 2 {
+            panic!("RESET power must be at least 2");
+        }
+        let model = self.linear_model();
+        let powers: Vec<Vec<f64>> = (2..=power).map(|p| model.fitted.iter().map(|f| f.powi(p as i32)).collect()).collect();
+        let extended = fit_linear_model(&augment(&self.design(), &powers), &self.get_y());
+        let df1 = (extended.rank - model.rank) as f64;
+        let df2 = extended.df_residual;
+        let f = ((model.sse - extended.sse) / df1) / (extended.sse / df2);
+        SpecificationTest { test: "RESET".to_string(), statistic: f, df: vec![df1, df2], p_value: f_p_value(f, df1, df2) }
+    }
+
+    pub fn jarque_bera(&self) -> SpecificationTest {
+        let (statistic, p_value) = jarque_bera(&self.linear_model().residual);
+        SpecificationTest { test: "Jarque-Bera".to_string(), statistic, df: vec![2.0], p_value }
+    }
+
+    pub fn shapiro_wilk(&self) -> SpecificationTest {
+        let residual = self.linear_model().residual;
+        let (statistic, p_value) = shapiro_wilk(&residual);
+        SpecificationTest { test: "Shapiro-Wilk".to_string(), statistic, df: vec![residual.len() as f64], p_value }
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    pub fn calculate_durbin_watson(&self, method: String) -> JsValue {
+        self.durbin_watson(&method).to_js()
+    }
+
+    pub fn calculate_breusch_godfrey(&self, order: usize) -> JsValue {
+        self.breusch_godfrey(order).to_js()
+    }
+
+    pub fn calculate_breusch_pagan(&self, studentized: bool) -> JsValue {
+        self.breusch_pagan(studentized).to_js()
+    }
+
+    pub fn calculate_white(&self) -> JsValue {
+        self.white().to_js()
+    }
+
+    pub fn calculate_reset(&self, power: usize) -> JsValue {
+        self.reset(power).to_js()
+    }
+
+    pub fn calculate_jarque_bera(&self) -> JsValue {
+        self.jarque_bera().to_js()
+    }
+
+    pub fn calculate_shapiro_wilk(&self) -> JsValue {
+        self.shapiro_wilk().to_js()
+    }
+
+    // All residual tests in one table, with the Breusch-Godfrey order and the RESET power;
+    // Shapiro-Wilk is left out beyond 5000 cases
+    pub fn calculate_specification_tests(&self, order: usize, power: usize) -> JsValue {
+        let n = self.get_y().len();
+        let mut tests = vec![
+            self.durbin_watson(if n <= 500 { "exact" } else { "approximate" }),
+            self.breusch_godfrey(order),
+            self.breusch_pagan(false),
+            self.breusch_pagan(true),
+            self.white(),
+            self.reset(power),
+            self.jarque_bera(),
+        ];
+        if n <= 5000 {
+            tests.push(self.shapiro_wilk());
+        }
+        let rows = Array::new();
+        for test in tests.iter() {
+            rows.push(&test.to_js());
+        }
+        JsValue::from(rows)
+    }
+}
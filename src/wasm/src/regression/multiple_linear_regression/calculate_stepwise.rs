@@ -0,0 +1,237 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use nalgebra::DMatrix;
+use crate::MultipleLinearRegression;
+use crate::regression::linear_model::ols::fit_linear_model;
+use crate::regression::multiple_linear_regression::calculate_anova::f_p_value;
+
+// One step of a variable selection with the predictors (indices into x) entered and removed,
+// those in the model after the step, its Model Summary and the change from the previous step
+pub struct SelectionStep {
+    pub method: String,
+    pub entered: Vec<usize>,
+    pub removed: Vec<usize>,
+    pub selected: Vec<usize>,
+    pub rank: f64,
+    pub r2: f64,
+    pub r2_adj: f64,
+    pub se_estimate: f64,
+    pub aic: f64,
+    pub bic: f64,
+    pub r2_change: f64,
+    pub f_change: f64,
+    pub df1: f64,
+    pub df2: f64,
+    pub p_value: f64,
+}
+
+// Residual sum of squares, rank and information criterion of a candidate model
+struct Candidate {
+    sse: f64,
+    rank: f64,
+}
+
+impl Candidate {
+    fn criterion(&self, criterion: &str, n: f64) -> f64 {
+        let penalty = match criterion {
+            "aic" => 2.0,
+            "bic" => n.ln(),
+            _ => panic!("Unknown selection criterion: {}", criterion),
+        };
+        n * (self.sse / n).ln() + penalty * self.rank
+    }
+}
+
+impl MultipleLinearRegression {
+    // Fit of the constant and the selected predictors
+    fn candidate(&self, selected: &[usize]) -> Candidate {
+        let x = self.get_x_values();
+        let y = self.get_y();
+        let design = DMatrix::from_fn(y.len(), selected.len() + 1, |i, j| if j == 0 { 1.0 } else { x[selected[j - 1]][i] });
+        let model = fit_linear_model(&design, &y);
+        Candidate { sse: model.sse, rank: model.rank as f64 }
+    }
+
+    // Best predictor to enter: the largest F-to-enter, if its probability is at most entry, or
+    // with "aic" and "bic" the largest decrease of the criterion. Aliased candidates never enter
+    fn entry_candidate(&self, selected: &[usize], criterion: &str, entry: f64) -> Option<usize> {
+        let n = self.get_y().len() as f64;
+        let current = self.candidate(selected);
+        let mut best: Option<(usize, f64)> = None;
+        for j in (0..self.get_x_values().len()).filter(|j| !selected.contains(j)) {
+            let mut extended = selected.to_vec();
+            extended.push(j);
+            let next = self.candidate(&extended);
+            if next.rank <= current.rank {
+                continue;
+            }
+            // F is negated so that, as with the criteria, the smallest score is best
+            let score = if criterion == "f" {
+                -(current.sse - next.sse) / (next.sse / (n - next.rank))
+            } else {
+                next.criterion(criterion, n)
+            };
+            if best.is_none_or(|(_, value)| score < value) {
+                best = Some((j, score));
+            }
+        }
+        match best {
+            Some((j, score)) if criterion == "f" && f_p_value(-score, 1.0, n - current.rank - 1.0) <= entry => Some(j),
+            Some((j, score)) if criterion != "f" && score < current.criterion(criterion, n) => Some(j),
+            _ => None,
+        }
+    }
+
+    // Worst predictor to remove: the smallest F-to-remove, if its probability is at least removal,
+    // or with "aic" and "bic" the largest decrease of the criterion
+    fn removal_candidate(&self, selected: &[usize], criterion: &str, removal: f64) -> Option<usize> {
+        let n = self.get_y().len() as f64;
+        let current = self.candidate(selected);
+        let df2 = n - current.rank;
+        let mut best: Option<(usize, f64, f64)> = None;
+        for j in selected.iter() {
+            let reduced: Vec<usize> = selected.iter().filter(|i| *i != j).cloned().collect();
+            let next = self.candidate(&reduced);
+            let df1 = current.rank - next.rank;
+            let score = if criterion != "f" {
+                next.criterion(criterion, n)
+            } else if df1 == 0.0 {
+                // An aliased predictor adds nothing and is removed first
+                f64::NEG_INFINITY
+            } else {
+                ((next.sse - current.sse) / df1) / (current.sse / df2)
+            };
+            if best.is_none_or(|(_, value, _)| score < value) {
+                best = Some((*j, score, df1));
+            }
+        }
+        match best {
+            Some((j, _, df1)) if criterion == "f" && df1 == 0.0 => Some(j),
+            Some((j, score, df1)) if criterion == "f" && f_p_value(score, df1, df2) >= removal => Some(j),
+            Some((j, score, _)) if criterion != "f" && score < current.criterion(criterion, n) => Some(j),
+            _ => None,
+        }
+    }
+
+    fn selection_step(&self, method: &str, entered: Vec<usize>, removed: Vec<usize>, selected: &[usize], previous: Option<&SelectionStep>) -> SelectionStep {
+        let x = self.get_x_values();
+        let columns: Vec<Vec<f64>> = selected.iter().map(|j| x[*j].clone()).collect();
+        let n = self.get_y().len() as f64;
+        let candidate = self.candidate(selected);
+        let (r2, r2_adj, se_estimate) = if columns.is_empty() {
+            (0.0, 0.0, (self.calculate_sst() / (n - 1.0)).sqrt())
+        } else {
+            let mut model = MultipleLinearRegression::from_columns(columns, self.get_y());
+            model.calculate_regression();
+            (model.calculate_r2(), model.calculate_r2_adj(), model.calculate_mse().sqrt())
+        };
+
+        // Change from the previous step (the constant only before the first), tested in the larger model
+        let (previous_r2, previous_rank) = previous.map_or((0.0, 1.0), |p| (p.r2, p.rank));
+        let (large_r2, large_rank) = if candidate.rank >= previous_rank { (r2, candidate.rank) } else { (previous_r2, previous_rank) };
+        let df1 = (candidate.rank - previous_rank).abs();
+        let df2 = n - large_rank;
+        let f_change = ((r2 - previous_r2).abs() / df1) / ((1.0 - large_r2) / df2);
+        SelectionStep {
+            method: method.to_string(),
+            entered,
+            removed,
+            selected: selected.to_vec(),
+            rank: candidate.rank,
+            r2,
+            r2_adj,
+            se_estimate,
+            aic: candidate.criterion("aic", n),
+            bic: candidate.criterion("bic", n),
+            r2_change: r2 - previous_r2,
+            f_change,
+            df1,
+            df2,
+            p_value: f_p_value(f_change, df1, df2),
+        }
+    }
+
+    // Variable selection by method "enter", "stepwise", "forward", "backward" or "remove", with
+    // criterion "f" (probability of F entry and removal, e.g. 0.05 and 0.10) or "aic"/"bic".
+    // Backward starts from all predictors; remove takes out the predictors in remove in one step
+    pub fn stepwise(&self, method: &str, criterion: &str, entry: f64, removal: f64, remove: &[usize]) -> Vec<SelectionStep> {
+        let k = self.get_x_values().len();
+        if criterion == "f" && method == "stepwise" && entry > removal {
+            panic!("Entry probability must not exceed removal probability");
+        }
+        if criterion != "f" && criterion != "aic" && criterion != "bic" {
+            panic!("Unknown selection criterion: {}", criterion);
+        }
+        let mut steps: Vec<SelectionStep> = Vec::new();
+        let mut selected: Vec<usize> = Vec::new();
+        if matches!(method, "enter" | "backward" | "remove") {
+            selected = (0..k).collect();
+            steps.push(self.selection_step("Enter", selected.clone(), Vec::new(), &selected, None));
+        }
+        let label = match method {
+            "enter" => return steps,
+            "stepwise" => "Stepwise",
+            "forward" => "Forward",
+            "backward" => "Backward",
+            "remove" => {
+                if remove.iter().any(|j| *j >= k) {
+                    panic!("Predictor to remove out of range");
+                }
+                selected.retain(|j| !remove.contains(j));
+                let step = self.selection_step("Remove", Vec::new(), remove.to_vec(), &selected, steps.last());
+                steps.push(step);
+                return steps;
+            }
+            _ => panic!("Unknown selection method: {}", method),
+        };
+
+        // Stepwise checks removal before each entry; the step limit guards against cycling
+        for _ in 0..2 * k + 2 {
+            let removed = if method != "forward" { self.removal_candidate(&selected, criterion, removal) } else { None };
+            let step = if let Some(j) = removed {
+                selected.retain(|i| *i != j);
+                self.selection_step(label, Vec::new(), vec![j], &selected, steps.last())
+            } else if method == "backward" {
+                break;
+            } else if let Some(j) = self.entry_candidate(&selected, criterion, entry) {
+                selected.push(j);
+                self.selection_step(label, vec![j], Vec::new(), &selected, steps.last())
+            } else {
+                break;
+            };
+            steps.push(step);
+        }
+        steps
+    }
+}
+
+#[wasm_bindgen]
+impl MultipleLinearRegression {
+    // Step history: the Variables Entered/Removed and Model Summary of each step with its
+    // change statistics and information criteria; predictors are indices into x
+    pub fn calculate_stepwise(&self, method: String, criterion: String, entry: f64, removal: f64, remove: Vec<usize>) -> JsValue {
+        let rows = Array::new();
+        for (i, step) in self.stepwise(&method, &criterion, entry, removal, &remove).iter().enumerate() {
+            let row = Object::new();
+            let indices = |v: &Vec<usize>| serde_wasm_bindgen::to_value(v).unwrap();
+            Reflect::set(&row, &"Model".into(), &((i + 1) as f64).into()).unwrap();
+            Reflect::set(&row, &"Method".into(), &step.method.clone().into()).unwrap();
+            Reflect::set(&row, &"Variables Entered".into(), &indices(&step.entered)).unwrap();
+            Reflect::set(&row, &"Variables Removed".into(), &indices(&step.removed)).unwrap();
+            Reflect::set(&row, &"Variables in Model".into(), &indices(&step.selected)).unwrap();
+            Reflect::set(&row, &"R".into(), &step.r2.max(0.0).sqrt().into()).unwrap();
+            Reflect::set(&row, &"R Square".into(), &step.r2.into()).unwrap();
+            Reflect::set(&row, &"Adjusted R Square".into(), &step.r2_adj.into()).unwrap();
+            Reflect::set(&row, &"Std. Error of the Estimate".into(), &step.se_estimate.into()).unwrap();
+            Reflect::set(&row, &"AIC".into(), &step.aic.into()).unwrap();
+            Reflect::set(&row, &"BIC".into(), &step.bic.into()).unwrap();
+            Reflect::set(&row, &"R Square Change".into(), &step.r2_change.into()).unwrap();
+            Reflect::set(&row, &"F Change".into(), &step.f_change.into()).unwrap();
+            Reflect::set(&row, &"df1".into(), &step.df1.into()).unwrap();
+            Reflect::set(&row, &"df2".into(), &step.df2.into()).unwrap();
+            Reflect::set(&row, &"Sig. F Change".into(), &step.p_value.into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
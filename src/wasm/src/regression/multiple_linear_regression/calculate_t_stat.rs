@@ -16,7 +16,7 @@ impl MultipleLinearRegression{
 
     pub fn calculate_pvalue(&self)-> Vec<f64>{
         let t_stat = self.calculate_t_stat();
-        let df = self.get_y().len() as f64 - self.get_beta().len() as f64;
+        let df = self.inference_df();
         let t_dist = StudentsT::new(0.0, 1.0, df).unwrap();
         let mut p_value = Vec::new();
         for t in t_stat.iter(){
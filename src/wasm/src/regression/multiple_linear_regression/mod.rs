@@ -3,4 +3,12 @@ pub mod calculate_matrix;
 pub mod calculate_regression;
 pub mod calculate_standard_error;
 pub mod calculate_t_stat;
-pub mod calculate_eval;
\ No newline at end of file
+pub mod calculate_eval;
+pub mod calculate_anova;
+pub mod calculate_r2_change;
+pub mod calculate_interval;
+pub mod calculate_diagnostics;
+pub mod calculate_collinearity;
+pub mod calculate_specification_test;
+pub mod calculate_robust_covariance;
+pub mod calculate_stepwise;
\ No newline at end of file
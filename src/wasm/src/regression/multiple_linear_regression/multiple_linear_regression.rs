@@ -9,6 +9,11 @@ pub struct MultipleLinearRegression {
     y: Vec<f64>,
     y_prediction: Vec<f64>,
     beta: Vec<f64>,
+    aliased: Vec<usize>,                  // Design columns (0 is the constant) left out as linear combinations of earlier ones
+    rank: usize,
+    unscaled_covariance: Vec<Vec<f64>>,   // (X'X)^-1 of the estimable coefficients, NaN for aliased ones
+    covariance_type: String,              // "classical", "hc0" to "hc4", "hac" or "cluster"
+    cluster: Vec<f64>,                    // Cluster ID of each case for cluster-robust standard errors
 }
 
 #[wasm_bindgen]
@@ -16,12 +21,7 @@ impl MultipleLinearRegression {
     #[wasm_bindgen(constructor)]
     pub fn new(x:JsValue, y: Vec<f64>) -> MultipleLinearRegression {
         let x: Vec<Vec<f64>> = from_value(x).unwrap(); 
-        MultipleLinearRegression {
-            x,
-            y,
-            y_prediction: Vec::new(),
-            beta: Vec::new(),
-        }
+        MultipleLinearRegression::from_columns(x, y)
     }
 
     #[wasm_bindgen(getter)]
@@ -37,6 +37,18 @@ impl MultipleLinearRegression {
     pub fn get_beta(&self) -> Vec<f64> {
         self.beta.clone()
     }
+    pub fn get_aliased(&self) -> Vec<usize> {
+        self.aliased.clone()
+    }
+    pub fn get_rank(&self) -> usize {
+        self.rank
+    }
+    pub fn get_covariance_type(&self) -> String {
+        self.covariance_type.clone()
+    }
+    pub fn get_cluster(&self) -> Vec<f64> {
+        self.cluster.clone()
+    }
 
     // Setters
     pub fn set_y_prediction(&mut self, y_prediction: Vec<f64>) {
@@ -45,4 +57,49 @@ impl MultipleLinearRegression {
     pub fn set_beta(&mut self, beta: Vec<f64>) {
         self.beta = beta;
     }
+    pub fn set_aliased(&mut self, aliased: Vec<usize>) {
+        self.aliased = aliased;
+    }
+    pub fn set_rank(&mut self, rank: usize) {
+        self.rank = rank;
+    }
+    pub fn set_covariance_type(&mut self, covariance_type: String) {
+        match covariance_type.as_str() {
+            "classical" | "hc0" | "hc1" | "hc2" | "hc3" | "hc4" | "hac" | "cluster" => self.covariance_type = covariance_type,
+            _ => panic!("Unknown covariance type: {}", covariance_type),
+        }
+    }
+    pub fn set_cluster(&mut self, cluster: Vec<f64>) {
+        if cluster.iter().any(|id| !id.is_finite()) {
+            panic!("Cluster IDs must be finite; remove cases with a missing cluster");
+        }
+        self.cluster = cluster;
+    }
+}
+
+impl MultipleLinearRegression {
+    // Regression on predictor columns given from Rust, without the JsValue round trip
+    pub fn from_columns(x: Vec<Vec<f64>>, y: Vec<f64>) -> MultipleLinearRegression {
+        MultipleLinearRegression {
+            x,
+            y,
+            y_prediction: Vec::new(),
+            beta: Vec::new(),
+            aliased: Vec::new(),
+            rank: 0,
+            unscaled_covariance: Vec::new(),
+            covariance_type: "classical".to_string(),
+            cluster: Vec::new(),
+        }
+    }
+
+    pub fn get_x_values(&self) -> Vec<Vec<f64>> {
+        self.x.clone()
+    }
+    pub fn get_unscaled_covariance(&self) -> Vec<Vec<f64>> {
+        self.unscaled_covariance.clone()
+    }
+    pub fn set_unscaled_covariance(&mut self, unscaled_covariance: Vec<Vec<f64>>) {
+        self.unscaled_covariance = unscaled_covariance;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,40 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use crate::QuadraticRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::interval::LinearDesign;
+
+impl LinearDesign for QuadraticRegression {
+    type Predictors = Vec<f64>;
+
+    fn predictors(&self) -> Vec<f64> {
+        self.get_x()
+    }
+
+    fn response(&self) -> Vec<f64> {
+        self.get_y()
+    }
+
+    // Design matrix of the constant, x and x^2
+    fn design(x: &Vec<f64>) -> DMatrix<f64> {
+        design_matrix(x.len(), &[x.clone(), x.iter().map(|v| v.powi(2)).collect()], true)
+    }
+}
+
+#[wasm_bindgen]
+impl QuadraticRegression {
+    // Confidence intervals of the coefficients at the given level, e.g. 0.95
+    pub fn calculate_confidence_interval(&self, level: f64) -> JsValue {
+        self.confidence_interval_js(level)
+    }
+
+    // Predicted values at new x
+    pub fn predict(&self, new_x: Vec<f64>) -> Vec<f64> {
+        self.predict_values(&new_x)
+    }
+
+    // Confidence interval of the mean response and prediction interval at new x
+    pub fn calculate_prediction_interval(&self, new_x: Vec<f64>, level: f64) -> JsValue {
+        self.prediction_interval_js(&new_x, level)
+    }
+}
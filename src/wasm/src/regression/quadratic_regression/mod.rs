@@ -1,2 +1,3 @@
 pub mod quadratic_regression;
-pub mod calculate_regression;
\ No newline at end of file
+pub mod calculate_regression;
+pub mod calculate_interval;
\ No newline at end of file
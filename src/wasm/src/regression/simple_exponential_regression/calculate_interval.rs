@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use crate::SimpleExponentialRegression;
+use crate::regression::linear_model::linear_model::design_matrix;
+use crate::regression::linear_model::interval::{interval_js, LinearDesign};
+
+// Linear model of ln y on x
+impl LinearDesign for SimpleExponentialRegression {
+    type Predictors = Vec<f64>;
+
+    fn predictors(&self) -> Vec<f64> {
+        self.get_x()
+    }
+
+    fn response(&self) -> Vec<f64> {
+        self.get_y().iter().map(|y| y.ln()).collect()
+    }
+
+    // Design matrix of the constant and x
+    fn design(x: &Vec<f64>) -> DMatrix<f64> {
+        design_matrix(x.len(), std::slice::from_ref(x), true)
+    }
+
+    // Intervals computed on the log scale and exponentiated, so the predicted value is the
+    // median response; the standard error is that of the log prediction
+    fn prediction_interval_js(&self, new_x: &Vec<f64>, level: f64) -> JsValue {
+        self.linear_model().prediction_interval(&Self::design(new_x), level).exp().to_js()
+    }
+}
+
+#[wasm_bindgen]
+impl SimpleExponentialRegression {
+    // Confidence intervals of b0 and b1 at the given level; the bounds of b0 are those of
+    // ln b0 exponentiated
+    pub fn calculate_confidence_interval(&self, level: f64) -> JsValue {
+        let (mut lower, mut upper) = self.linear_model().coefficient_interval(level);
+        lower[0] = lower[0].exp();
+        upper[0] = upper[0].exp();
+        interval_js(&lower, &upper)
+    }
+
+    // Predicted values b0 * e^(b1 * x) at new x
+    pub fn predict(&self, new_x: Vec<f64>) -> Vec<f64> {
+        new_x.iter().map(|x| self.get_b0() * (self.get_b1() * x).exp()).collect()
+    }
+
+    pub fn calculate_prediction_interval(&self, new_x: Vec<f64>, level: f64) -> JsValue {
+        self.prediction_interval_js(&new_x, level)
+    }
+}
@@ -2,4 +2,5 @@ pub mod simple_linear_regression;
 pub mod calculate_regression;
 pub mod calculate_standard_error;
 pub mod calculate_t_stat;
-pub mod calculate_eval;
\ No newline at end of file
+pub mod calculate_eval;
+pub mod calculate_interval;
\ No newline at end of file
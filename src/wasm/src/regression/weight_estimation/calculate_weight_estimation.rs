@@ -0,0 +1,155 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{ContinuousCDF, StudentsT};
+use std::f64::consts::PI;
+use crate::WeightEstimation;
+use crate::regression::linear_model::linear_model::{design_matrix, LinearModel};
+use crate::regression::linear_model::ols::fit_weighted_linear_model;
+use crate::regression::multiple_linear_regression::calculate_anova::f_p_value;
+
+// Normal log-likelihood of a weighted fit where case i has variance sigma^2 / w_i, at the
+// maximum likelihood sigma^2 = sum w e^2 / n
+pub fn weighted_log_likelihood(model: &LinearModel, weight: &[f64]) -> f64 {
+    let positive: Vec<f64> = weight.iter().filter(|w| **w > 0.0).cloned().collect();
+    let n = positive.len() as f64;
+    -n / 2.0 * ((2.0 * PI).ln() + (model.sse / n).ln() + 1.0) + positive.iter().map(|w| w.ln()).sum::<f64>() / 2.0
+}
+
+// Weighted mean and sum of squared deviations of a variable
+fn weighted_moments(v: &[f64], weight: &[f64]) -> (f64, f64) {
+    let total: f64 = weight.iter().sum();
+    let mean = v.iter().zip(weight.iter()).map(|(a, w)| a * w).sum::<f64>() / total;
+    (mean, v.iter().zip(weight.iter()).map(|(a, w)| w * (a - mean).powi(2)).sum())
+}
+
+impl WeightEstimation {
+    pub fn weighted_model(&self, weight: &[f64]) -> LinearModel {
+        fit_weighted_linear_model(&design_matrix(self.get_y().len(), &self.get_x_values(), true), &self.get_y(), weight)
+    }
+
+    fn power_weight(&self, power: f64) -> Vec<f64> {
+        self.get_source().iter().map(|s| 1.0 / s.powf(power)).collect()
+    }
+
+    // Weighted sums of squares: regression, residual and total, with degrees of freedom
+    fn anova_components(&self) -> ([f64; 3], [f64; 3]) {
+        let weight = self.get_weight();
+        let model = self.weighted_model(&weight);
+        let (_, sst) = weighted_moments(&self.get_y(), &weight);
+        let n = weight.iter().filter(|w| **w > 0.0).count() as f64;
+        let rank = model.rank as f64;
+        ([sst - model.sse, model.sse, sst], [rank - 1.0, n - rank, n - 1.0])
+    }
+}
+
+#[wasm_bindgen]
+impl WeightEstimation {
+    // Weighted least squares with the given case weights
+    pub fn calculate_weighted_regression(&mut self, weight: Vec<f64>) {
+        let model = self.weighted_model(&weight);
+        self.set_coefficient(model.coefficient);
+        self.set_weight(weight);
+    }
+
+    // SPSS weight estimation: weights 1 / source^power for the powers start, start + step, ...
+    // up to stop, keeping the power with the largest log-likelihood for the final fit
+    pub fn calculate_weight_estimation(&mut self, start: f64, stop: f64, step: f64) {
+        if step <= 0.0 || stop < start {
+            panic!("Power range needs start <= stop and a positive step");
+        }
+        if self.get_source().iter().any(|s| *s <= 0.0) {
+            panic!("Weight source variable must be positive");
+        }
+        let count = ((stop - start) / step + 1e-9).floor() as usize + 1;
+        let power: Vec<f64> = (0..count).map(|i| start + i as f64 * step).collect();
+        let log_likelihood: Vec<f64> = power
+            .iter()
+            .map(|p| {
+                let weight = self.power_weight(*p);
+                weighted_log_likelihood(&self.weighted_model(&weight), &weight)
+            })
+            .collect();
+        let best = (0..count).fold(0, |best, i| if log_likelihood[i] > log_likelihood[best] { i } else { best });
+        self.set_best_power(power[best]);
+        self.set_power(power);
+        self.set_log_likelihood(log_likelihood);
+        self.calculate_weighted_regression(self.power_weight(self.get_best_power()));
+    }
+
+    // Log-likelihood Values table, one row per power
+    pub fn calculate_log_likelihood_table(&self) -> JsValue {
+        let rows = Array::new();
+        for (power, log_likelihood) in self.get_power().iter().zip(self.get_log_likelihood().iter()) {
+            let row = Object::new();
+            Reflect::set(&row, &"Power".into(), &(*power).into()).unwrap();
+            Reflect::set(&row, &"Log-likelihood".into(), &(*log_likelihood).into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+
+    // Model Description / Summary of the final weighted fit
+    pub fn calculate_model_summary(&self) -> JsValue {
+        let weight = self.get_weight();
+        let model = self.weighted_model(&weight);
+        let (ss, df) = self.anova_components();
+        let r2 = 1.0 - ss[1] / ss[2];
+        let results = Object::new();
+        Reflect::set(&results, &"Power".into(), &self.get_best_power().into()).unwrap();
+        Reflect::set(&results, &"Multiple R".into(), &r2.max(0.0).sqrt().into()).unwrap();
+        Reflect::set(&results, &"R Square".into(), &r2.into()).unwrap();
+        Reflect::set(&results, &"Adjusted R Square".into(), &(1.0 - (1.0 - r2) * df[2] / df[1]).into()).unwrap();
+        Reflect::set(&results, &"Std. Error of the Estimate".into(), &model.mse().sqrt().into()).unwrap();
+        Reflect::set(&results, &"Log-likelihood Function Value".into(), &weighted_log_likelihood(&model, &weight).into()).unwrap();
+        JsValue::from(results)
+    }
+
+    // ANOVA table of the weighted sums of squares
+    pub fn calculate_anova(&self) -> JsValue {
+        let (ss, df) = self.anova_components();
+        let f = (ss[0] / df[0]) / (ss[1] / df[1]);
+        let rows = Array::new();
+        for (i, source) in ["Regression", "Residual", "Total"].iter().enumerate() {
+            let row = Object::new();
+            Reflect::set(&row, &"Source".into(), &(*source).into()).unwrap();
+            Reflect::set(&row, &"Sum of Squares".into(), &ss[i].into()).unwrap();
+            Reflect::set(&row, &"df".into(), &df[i].into()).unwrap();
+            if i < 2 {
+                Reflect::set(&row, &"Mean Square".into(), &(ss[i] / df[i]).into()).unwrap();
+            }
+            if i == 0 {
+                Reflect::set(&row, &"F".into(), &f.into()).unwrap();
+                Reflect::set(&row, &"Sig.".into(), &f_p_value(f, df[0], df[1]).into()).unwrap();
+            }
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+
+    // Coefficients table: B, Std. Error, standardized Beta from weighted standard deviations, t and Sig.
+    pub fn calculate_coefficients(&self) -> JsValue {
+        let weight = self.get_weight();
+        let model = self.weighted_model(&weight);
+        let se = model.standard_error();
+        let x = self.get_x_values();
+        let (_, ss_y) = weighted_moments(&self.get_y(), &weight);
+        let t_dist = StudentsT::new(0.0, 1.0, model.df_residual).unwrap();
+        let rows = Array::new();
+        for (j, b) in model.coefficient.iter().enumerate() {
+            let t = b / se[j];
+            let row = Object::new();
+            let name = if j == 0 { "(Constant)".to_string() } else { format!("X{}", j) };
+            Reflect::set(&row, &"Variable".into(), &name.into()).unwrap();
+            Reflect::set(&row, &"B".into(), &(*b).into()).unwrap();
+            Reflect::set(&row, &"Std. Error".into(), &se[j].into()).unwrap();
+            if j > 0 {
+                let (_, ss_x) = weighted_moments(&x[j - 1], &weight);
+                Reflect::set(&row, &"Beta".into(), &(b * (ss_x / ss_y).sqrt()).into()).unwrap();
+            }
+            Reflect::set(&row, &"t".into(), &t.into()).unwrap();
+            Reflect::set(&row, &"Sig.".into(), &(2.0 * (1.0 - t_dist.cdf(t.abs()))).into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
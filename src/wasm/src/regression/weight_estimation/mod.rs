@@ -0,0 +1,2 @@
+pub mod weight_estimation;
+pub mod calculate_weight_estimation;
\ No newline at end of file
@@ -0,0 +1,85 @@
+use wasm_bindgen::prelude::*;
+use serde_wasm_bindgen::{from_value, to_value};
+
+#[wasm_bindgen]
+pub struct WeightEstimation {
+    x: Vec<Vec<f64>>,
+    y: Vec<f64>,
+    source: Vec<f64>,          // Weight source variable, weights are 1 / source^power
+    power: Vec<f64>,           // Powers searched
+    log_likelihood: Vec<f64>,  // Log-likelihood at each power
+    best_power: f64,
+    weight: Vec<f64>,          // Case weights of the final fit
+    coefficient: Vec<f64>,     // Constant first, NaN for aliased predictors
+}
+
+#[wasm_bindgen]
+impl WeightEstimation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(x: JsValue, y: Vec<f64>, source: Vec<f64>) -> WeightEstimation {
+        let x: Vec<Vec<f64>> = from_value(x).unwrap();
+        WeightEstimation::from_columns(x, y, source)
+    }
+
+    // Getters
+    pub fn get_x(&self) -> JsValue {
+        to_value(&self.x).unwrap()
+    }
+    pub fn get_y(&self) -> Vec<f64> {
+        self.y.clone()
+    }
+    pub fn get_source(&self) -> Vec<f64> {
+        self.source.clone()
+    }
+    pub fn get_power(&self) -> Vec<f64> {
+        self.power.clone()
+    }
+    pub fn get_log_likelihood(&self) -> Vec<f64> {
+        self.log_likelihood.clone()
+    }
+    pub fn get_best_power(&self) -> f64 {
+        self.best_power
+    }
+    pub fn get_weight(&self) -> Vec<f64> {
+        self.weight.clone()
+    }
+    pub fn get_coefficient(&self) -> Vec<f64> {
+        self.coefficient.clone()
+    }
+
+    // Setters
+    pub fn set_power(&mut self, power: Vec<f64>) {
+        self.power = power;
+    }
+    pub fn set_log_likelihood(&mut self, log_likelihood: Vec<f64>) {
+        self.log_likelihood = log_likelihood;
+    }
+    pub fn set_best_power(&mut self, best_power: f64) {
+        self.best_power = best_power;
+    }
+    pub fn set_weight(&mut self, weight: Vec<f64>) {
+        self.weight = weight;
+    }
+    pub fn set_coefficient(&mut self, coefficient: Vec<f64>) {
+        self.coefficient = coefficient;
+    }
+}
+
+impl WeightEstimation {
+    pub fn from_columns(x: Vec<Vec<f64>>, y: Vec<f64>, source: Vec<f64>) -> WeightEstimation {
+        WeightEstimation {
+            x,
+            y,
+            source,
+            power: Vec::new(),
+            log_likelihood: Vec::new(),
+            best_power: f64::NAN,
+            weight: Vec::new(),
+            coefficient: Vec::new(),
+        }
+    }
+
+    pub fn get_x_values(&self) -> Vec<Vec<f64>> {
+        self.x.clone()
+    }
+}
@@ -10,6 +10,10 @@ pub struct Arima {
     constant: f64,
     ar_coef: Vec<f64>,
     ma_coef: Vec<f64>,
+    missing_index: Vec<usize>,
+    transformation: String,  // "none", "box_cox" or "yeo_johnson"; data then holds the transformed series
+    lambda: f64,
+    original_data: Vec<f64>,
 }
 
 #[wasm_bindgen]
@@ -25,6 +29,10 @@ impl Arima{
             constant: 0.0,
             ar_coef: Vec::new(),
             ma_coef: Vec::new(),
+            missing_index: Vec::new(),
+            transformation: "none".to_string(),
+            lambda: 1.0,
+            original_data: Vec::new(),
         }
     }
 
@@ -53,6 +61,22 @@ impl Arima{
     pub fn get_ma_coef(&self) -> Vec<f64>{
         self.ma_coef.clone()
     }
+    pub fn get_missing_index(&self) -> Vec<usize>{
+        self.missing_index.clone()
+    }
+    pub fn get_transformation(&self) -> String{
+        self.transformation.clone()
+    }
+    pub fn get_lambda(&self) -> f64{
+        self.lambda
+    }
+    pub fn get_original_data(&self) -> Vec<f64>{
+        if self.transformation == "none" {
+            self.data.clone()
+        } else {
+            self.original_data.clone()
+        }
+    }
 
     // Setters
     pub fn set_data(&mut self, data: Vec<f64>){
@@ -70,4 +94,16 @@ impl Arima{
     pub fn set_ma_coef(&mut self, ma_coef: Vec<f64>){
         self.ma_coef = ma_coef;
     }
+    pub fn set_missing_index(&mut self, missing_index: Vec<usize>){
+        self.missing_index = missing_index;
+    }
+    pub fn set_transformation(&mut self, transformation: String){
+        self.transformation = transformation;
+    }
+    pub fn set_lambda(&mut self, lambda: f64){
+        self.lambda = lambda;
+    }
+    pub fn set_original_data(&mut self, original_data: Vec<f64>){
+        self.original_data = original_data;
+    }
 }
\ No newline at end of file
@@ -5,6 +5,10 @@ use arima::estimate;
 #[wasm_bindgen]
 impl Arima{
     pub fn estimate_coef(&mut self) -> Vec<f64> {
+        // Missing values (NaN) are imputed before estimation
+        if self.get_data().iter().any(|x| x.is_nan()) {
+            self.impute_missing();
+        }
         let data = self.get_data();
         let p = self.get_ar_order() as usize;
         let q = self.get_ma_order() as usize;
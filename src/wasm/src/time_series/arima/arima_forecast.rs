@@ -3,6 +3,7 @@ use crate::Arima;
 use arima::sim;
 use rand_distr::{Distribution, Normal};
 use rand::thread_rng;
+use crate::time_series::transformation::power_transform::back_transform;
 
 #[wasm_bindgen]
 impl Arima{
@@ -19,6 +20,7 @@ impl Arima{
             &|_, mut rng| { normal.sample(&mut rng) },
             &mut thread_rng()
         ).unwrap();
-        forecast
+        // Simulated paths of a transformed series are mapped back to the original scale
+        back_transform(&self.get_transformation(), self.get_lambda(), &forecast, &[])
     }
 }
\ No newline at end of file
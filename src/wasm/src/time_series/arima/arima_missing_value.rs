@@ -0,0 +1,20 @@
+use wasm_bindgen::prelude::*;
+use crate::Arima;
+use crate::time_series::missing_value::kalman_imputation::arima_imputation;
+
+#[wasm_bindgen]
+impl Arima{
+    // Replace NaN values by their Kalman smoothed estimates under the ARIMA model
+    // and return the imputed positions
+    pub fn impute_missing(&mut self) -> Vec<usize>{
+        let data = self.get_data();
+        let missing_index: Vec<usize> = (0..data.len()).filter(|i| data[*i].is_nan()).collect();
+        if missing_index.is_empty() {
+            return self.get_missing_index();
+        }
+        let imputed = arima_imputation(&data, self.get_ar_order(), self.get_i_order(), self.get_ma_order());
+        self.set_data(imputed);
+        self.set_missing_index(missing_index.clone());
+        missing_index
+    }
+}
@@ -0,0 +1,80 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, first_difference};
+use crate::time_series::outlier_detection::outlier_effect::{pi_weights, psi_weights};
+use crate::time_series::transformation::power_transform::*;
+use crate::time_series::transformation::lambda_estimation::estimate_lambda;
+
+#[wasm_bindgen]
+impl Arima{
+    // Fit on the Box-Cox or Yeo-Johnson scale from now on; lambda_method is "guerrero",
+    // "likelihood" or "manual" (use the given lambda). Returns the lambda used
+    pub fn transform_data(&mut self, method: String, lambda_method: String, lambda: f64, period: usize) -> f64{
+        let data = self.get_original_data();
+        if method == "box_cox" && data.iter().any(|x| *x <= 0.0) {
+            panic!("Box-Cox transformation needs positive data");
+        }
+        let lambda = estimate_lambda(&data, &method, &lambda_method, lambda, period);
+        let transformed: Vec<f64> = data.iter().map(|x| transform(&method, *x, lambda)).collect();
+        self.set_original_data(data);
+        self.set_transformation(method);
+        self.set_lambda(lambda);
+        self.set_data(transformed);
+        lambda
+    }
+
+    // Variance of the 1..n step forecast errors on the scale the model is fitted on
+    pub fn forecast_variance(&self, n: usize) -> Vec<f64>{
+        let pi = pi_weights(&self.get_ar_coef(), &self.get_ma_coef(), self.get_i_order() as usize, n.max(1));
+        let psi = psi_weights(&pi, n);
+        let res_var = self.res_variance();
+        psi.iter()
+            .scan(0.0, |sum, weight| {
+                *sum += weight * weight;
+                Some(*sum * res_var)
+            })
+            .collect()
+    }
+
+    // Mean forecast on the original scale, bias adjusted when the data is transformed
+    pub fn point_forecast(&self, n: usize) -> Vec<f64>{
+        back_transform(&self.get_transformation(), self.get_lambda(), &self.model_forecast(n), &self.forecast_variance(n))
+    }
+
+    // Mean forecast on the scale the model is fitted on
+    pub fn model_forecast(&self, n: usize) -> Vec<f64>{
+        // Forecast the differenced series with future errors set to zero
+        let d = self.get_i_order() as usize;
+        let mut levels: Vec<Vec<f64>> = vec![self.get_data()];
+        for k in 0..d {
+            levels.push(first_difference(levels[k].clone()));
+        }
+        let mut w = levels[d].clone();
+        let mut e = self.estimate_residual();
+        let ar_coef = self.get_ar_coef();
+        let ma_coef = self.get_ma_coef();
+        let constant = self.get_constant();
+        for _ in 0..n {
+            let t = w.len();
+            let mut value = constant;
+            for (j, phi) in ar_coef.iter().enumerate() {
+                value += phi * w.get(t.wrapping_sub(j + 1)).copied().unwrap_or(0.0);
+            }
+            for (j, theta) in ma_coef.iter().enumerate() {
+                value += theta * e.get(t.wrapping_sub(j + 1)).copied().unwrap_or(0.0);
+            }
+            w.push(value);
+            e.push(0.0);
+        }
+
+        // Undo the differencing one level at a time
+        let mut forecast: Vec<f64> = w[w.len() - n..].to_vec();
+        for k in (0..d).rev() {
+            let mut last = *levels[k].last().unwrap();
+            for value in forecast.iter_mut() {
+                last += *value;
+                *value = last;
+            }
+        }
+        forecast
+    }
+}
@@ -7,7 +7,7 @@ use crate::time_series::evaluation::basic_evaluation::*;
 #[wasm_bindgen]
 impl Arima{
     pub fn forecasting_evaluation(&self)-> JsValue{
-        let data = self.get_data();
+        let data = self.get_original_data();
         let forecast = self.forecast(data.len());
         assert_eq!(data.len(), forecast.len());
 
@@ -5,4 +5,6 @@ pub mod arima_estimate_se;
 pub mod arima_forecast;
 pub mod selection_criteria;
 pub mod forecasting_evaluation;
-pub mod arima_z_test;
\ No newline at end of file
+pub mod arima_z_test;
+pub mod arima_missing_value;
+pub mod arima_transformation;
\ No newline at end of file
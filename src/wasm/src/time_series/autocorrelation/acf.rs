@@ -9,7 +9,10 @@ impl Autocorrelation{
         let lag = self.get_lag();
         let mut autocorrelation = Vec::new();
         let n = data.len();
-        let mean = data.iter().sum::<f64>() / n as f64;
+        // Missing values (NaN) are set to the mean of the observed values so they add nothing
+        let observed: Vec<f64> = data.iter().cloned().filter(|x| !x.is_nan()).collect();
+        let mean = observed.iter().sum::<f64>() / observed.len() as f64;
+        let data: Vec<f64> = data.iter().map(|x| if x.is_nan() { mean } else { *x }).collect();
         let mut numerator = 0.0;
         let mut denominator = 0.0;
         // Calculate the autocorrelation
@@ -31,7 +34,7 @@ impl Autocorrelation{
         for i in 0..self.get_lag(){
             let autocorelate_pow_2 = autocorelate[0..i as usize].iter().map(|x| x.powi(2)).collect::<Vec<f64>>();
             let total = autocorelate_pow_2[0..i as usize].iter().sum::<f64>();
-            let se = sqrt((1.0 + (2.0 * total)) / self.get_data().len() as f64) as f64;
+            let se = sqrt((1.0 + (2.0 * total)) / self.get_observed_count() as f64) as f64;
             autocorrelation_se.push(se);
         }
         autocorrelation_se
@@ -63,6 +63,10 @@ impl Autocorrelation{
     pub fn get_pvalue_lb(&self) -> Vec<f64>{
         self.pvalue_lb.clone()
     }
+    // Number of non-missing (non-NaN) observations
+    pub fn get_observed_count(&self) -> usize{
+        self.data.iter().filter(|x| !x.is_nan()).count()
+    }
 
     // Setters
     pub fn set_data(&mut self, data: Vec<f64>){
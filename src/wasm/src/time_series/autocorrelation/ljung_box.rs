@@ -7,7 +7,7 @@ use statrs::distribution::{ChiSquared, ContinuousCDF};
 impl Autocorrelation{
     pub fn calculate_ljung_box(&self, autocorrelate: Vec<f64>) -> Vec<f64>{
         let mut ljung_box = Vec::new();
-        let n = self.get_data().len() as f64;
+        let n = self.get_observed_count() as f64;
         for i in 1..self.get_lag() + 1{
             let mut var_corr = 0.0;
             for j in 0..i{
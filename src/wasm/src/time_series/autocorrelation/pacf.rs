@@ -40,7 +40,7 @@ impl Autocorrelation{
     pub fn calculate_pacf_se(&self, partial_autocorelate: Vec<f64>) -> Vec<f64>{
         let mut partial_autocorrelation_se: Vec<f64> = Vec::new();
         for _i in 0..partial_autocorelate.len(){
-            let se = sqrt(1.0 / self.get_data().len() as f64) as f64;
+            let se = sqrt(1.0 / self.get_observed_count() as f64) as f64;
             partial_autocorrelation_se.push(se);
         }
         partial_autocorrelation_se
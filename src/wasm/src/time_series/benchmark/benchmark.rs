@@ -0,0 +1,97 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Benchmark {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    period: i32,
+    fitted: Vec<f64>,           // One-step forecasts over the sample, NaN where undefined
+    forecast: Vec<f64>,
+    lower_bound: Vec<f64>,      // Prediction interval of the forecasts
+    upper_bound: Vec<f64>,
+    forecast_time: Vec<String>, // Labels of the forecast periods, empty when the time labels are not parsed
+    alpha: f64,                 // Smoothing constant of the Theta method
+}
+
+#[wasm_bindgen]
+impl Benchmark {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String, period: i32) -> Benchmark {
+        Benchmark {
+            data,
+            data_header,
+            time,
+            time_header,
+            period,
+            fitted: Vec::new(),
+            forecast: Vec::new(),
+            lower_bound: Vec::new(),
+            upper_bound: Vec::new(),
+            forecast_time: Vec::new(),
+            alpha: 0.0,
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String {
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String> {
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String {
+        self.time_header.clone()
+    }
+    pub fn get_period(&self) -> i32 {
+        self.period
+    }
+    pub fn get_fitted(&self) -> Vec<f64> {
+        self.fitted.clone()
+    }
+    pub fn get_forecast(&self) -> Vec<f64> {
+        self.forecast.clone()
+    }
+    pub fn get_lower_bound(&self) -> Vec<f64> {
+        self.lower_bound.clone()
+    }
+    pub fn get_upper_bound(&self) -> Vec<f64> {
+        self.upper_bound.clone()
+    }
+    pub fn get_forecast_time(&self) -> Vec<String> {
+        self.forecast_time.clone()
+    }
+    pub fn get_alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_period(&mut self, period: i32) {
+        self.period = period;
+    }
+    pub fn set_fitted(&mut self, fitted: Vec<f64>) {
+        self.fitted = fitted;
+    }
+    pub fn set_forecast(&mut self, forecast: Vec<f64>) {
+        self.forecast = forecast;
+    }
+    pub fn set_lower_bound(&mut self, lower_bound: Vec<f64>) {
+        self.lower_bound = lower_bound;
+    }
+    pub fn set_upper_bound(&mut self, upper_bound: Vec<f64>) {
+        self.upper_bound = upper_bound;
+    }
+    pub fn set_forecast_time(&mut self, forecast_time: Vec<String>) {
+        self.forecast_time = forecast_time;
+    }
+    pub fn set_alpha(&mut self, alpha: f64) {
+        self.alpha = alpha;
+    }
+}
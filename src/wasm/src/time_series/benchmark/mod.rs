@@ -0,0 +1,6 @@
+pub mod benchmark;
+pub mod prediction_interval;
+pub mod simple_benchmark;
+pub mod theta;
+
+pub use benchmark::Benchmark;
\ No newline at end of file
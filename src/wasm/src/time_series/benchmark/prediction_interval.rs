@@ -0,0 +1,31 @@
+use statrs::distribution::{ContinuousCDF, Normal};
+use crate::{Benchmark, TimeIndex};
+
+// Standard normal quantile of a two-sided interval at the given level, e.g. 0.95
+pub fn interval_multiplier(level: f64) -> f64 {
+    if !(0.0..1.0).contains(&level) || level == 0.0 {
+        panic!("Interval level must be between 0 and 1");
+    }
+    Normal::new(0.0, 1.0).unwrap().inverse_cdf((1.0 + level) / 2.0)
+}
+
+// Residual standard deviation of the fitted values with the given number of estimated parameters
+pub fn residual_sd(data: &[f64], fitted: &[f64], parameters: usize) -> f64 {
+    let residual: Vec<f64> = data.iter().zip(fitted.iter()).map(|(y, f)| y - f).filter(|e| !e.is_nan()).collect();
+    let df = residual.len().saturating_sub(parameters).max(1);
+    (residual.iter().map(|e| e * e).sum::<f64>() / df as f64).sqrt()
+}
+
+impl Benchmark {
+    // Store the forecasts with their prediction interval and the labels of the forecast periods
+    pub fn store_forecast(&mut self, fitted: Vec<f64>, forecast: Vec<f64>, forecast_se: Vec<f64>, level: f64) {
+        let z = interval_multiplier(level);
+        self.set_lower_bound(forecast.iter().zip(forecast_se.iter()).map(|(f, se)| f - z * se).collect());
+        self.set_upper_bound(forecast.iter().zip(forecast_se.iter()).map(|(f, se)| f + z * se).collect());
+        let time_index = TimeIndex::new(self.get_time(), self.get_time_header());
+        let forecast_time = if time_index.get_frequency() == "unknown" { Vec::new() } else { time_index.generate_future_label(forecast.len()) };
+        self.set_forecast_time(forecast_time);
+        self.set_fitted(fitted);
+        self.set_forecast(forecast);
+    }
+}
@@ -0,0 +1,85 @@
+use wasm_bindgen::prelude::*;
+use crate::Benchmark;
+use crate::time_series::benchmark::prediction_interval::residual_sd;
+use crate::time_series::missing_value::interpolation::{locf, observed_points};
+
+#[wasm_bindgen]
+impl Benchmark {
+    // Naive: every forecast is the last observation
+    pub fn calculate_naive(&mut self, horizon: usize, level: f64) -> Vec<f64> {
+        let data = self.get_data();
+        let (index, values) = observed_points(&data);
+        if index.is_empty() {
+            panic!("Benchmark forecasts need at least one observation");
+        }
+        let filled = locf(&data);
+        let fitted: Vec<f64> = (0..data.len()).map(|t| if t == 0 { f64::NAN } else { filled[t - 1] }).collect();
+        let sd = residual_sd(&data, &fitted, 0);
+        let forecast = vec![values[values.len() - 1]; horizon];
+        let forecast_se: Vec<f64> = (1..=horizon).map(|h| sd * (h as f64).sqrt()).collect();
+        self.store_forecast(fitted, forecast.clone(), forecast_se, level);
+        forecast
+    }
+
+    // Seasonal naive: every forecast is the last observation of the same season
+    pub fn calculate_seasonal_naive(&mut self, horizon: usize, level: f64) -> Vec<f64> {
+        let data = self.get_data();
+        let m = self.get_period() as usize;
+        if m < 1 || data.len() < m {
+            panic!("Seasonal naive needs a period of at least 1 and one full season of data");
+        }
+        // Missing values take the value of the previous season
+        let mut filled: Vec<f64> = data.clone();
+        for t in m..filled.len() {
+            if filled[t].is_nan() {
+                filled[t] = filled[t - m];
+            }
+        }
+        let fitted: Vec<f64> = (0..data.len()).map(|t| if t < m { f64::NAN } else { filled[t - m] }).collect();
+        let sd = residual_sd(&data, &fitted, 0);
+        let n = data.len();
+        let forecast: Vec<f64> = (1..=horizon).map(|h| filled[n - m + (h - 1) % m]).collect();
+        let forecast_se: Vec<f64> = (1..=horizon).map(|h| sd * (((h - 1) / m + 1) as f64).sqrt()).collect();
+        self.store_forecast(fitted, forecast.clone(), forecast_se, level);
+        forecast
+    }
+
+    // Drift: the line through the first and last observations, extended
+    pub fn calculate_drift(&mut self, horizon: usize, level: f64) -> Vec<f64> {
+        let data = self.get_data();
+        let (index, values) = observed_points(&data);
+        if index.len() < 2 {
+            panic!("Drift needs at least two observations");
+        }
+        let (first, last) = (index[0], index[index.len() - 1]);
+        let slope = (values[values.len() - 1] - values[0]) / (last - first) as f64;
+        let filled = locf(&data);
+        let fitted: Vec<f64> = (0..data.len()).map(|t| if t == 0 { f64::NAN } else { filled[t - 1] + slope }).collect();
+        let sd = residual_sd(&data, &fitted, 1);
+        let count = index.len() as f64;
+        let steps = (data.len() - 1 - last) as f64;
+        let forecast: Vec<f64> = (1..=horizon).map(|h| values[values.len() - 1] + slope * (steps + h as f64)).collect();
+        let forecast_se: Vec<f64> = (1..=horizon).map(|h| {
+            let h = steps + h as f64;
+            sd * (h * (1.0 + h / count)).sqrt()
+        }).collect();
+        self.store_forecast(fitted, forecast.clone(), forecast_se, level);
+        forecast
+    }
+
+    // Mean: every forecast is the sample mean
+    pub fn calculate_mean(&mut self, horizon: usize, level: f64) -> Vec<f64> {
+        let data = self.get_data();
+        let (index, values) = observed_points(&data);
+        if index.len() < 2 {
+            panic!("Mean forecast needs at least two observations");
+        }
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        let fitted = vec![mean; data.len()];
+        let sd = residual_sd(&data, &fitted, 1);
+        let forecast = vec![mean; horizon];
+        let forecast_se = vec![sd * (1.0 + 1.0 / values.len() as f64).sqrt(); horizon];
+        self.store_forecast(fitted, forecast.clone(), forecast_se, level);
+        forecast
+    }
+}
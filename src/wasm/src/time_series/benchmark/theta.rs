@@ -0,0 +1,110 @@
+use wasm_bindgen::prelude::*;
+use crate::{Autocorrelation, Benchmark, Decomposition};
+use crate::time_series::benchmark::prediction_interval::residual_sd;
+use crate::time_series::missing_value::interpolation::linear_interpolation;
+
+// Seasonality test of the classical Theta method: the lag m autocorrelation is significant at 90%
+fn is_seasonal(data: &[f64], period: usize) -> bool {
+    let acf = Autocorrelation::new(data.to_vec(), String::new(), period as i32).calculate_acf(data.to_vec());
+    let sum: f64 = acf[..period - 1].iter().map(|r| r * r).sum();
+    let limit = 1.645 * ((1.0 + 2.0 * sum) / data.len() as f64).sqrt();
+    acf[period - 1].abs() > limit
+}
+
+// Simple exponential smoothing from the first value: one-step forecasts and the final level
+fn ses(data: &[f64], alpha: f64) -> (Vec<f64>, f64) {
+    let mut fitted = vec![f64::NAN; data.len()];
+    let mut level = data[0];
+    for t in 1..data.len() {
+        fitted[t] = level;
+        level += alpha * (data[t] - level);
+    }
+    (fitted, level)
+}
+
+fn ses_sse(data: &[f64], alpha: f64) -> f64 {
+    let (fitted, _) = ses(data, alpha);
+    data.iter().zip(fitted.iter()).skip(1).map(|(y, f)| (y - f).powi(2)).sum()
+}
+
+// Smoothing constant minimising the one-step squared errors, by grid search and golden section
+fn optimal_alpha(data: &[f64]) -> f64 {
+    let mut best = 0.01;
+    let mut best_sse = f64::INFINITY;
+    for k in 1..100 {
+        let alpha = k as f64 / 100.0;
+        let sse = ses_sse(data, alpha);
+        if sse < best_sse {
+            best_sse = sse;
+            best = alpha;
+        }
+    }
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut lower, mut upper) = ((best - 0.01).max(0.0001), (best + 0.01).min(0.9999));
+    for _ in 0..30 {
+        let x1 = upper - ratio * (upper - lower);
+        let x2 = lower + ratio * (upper - lower);
+        if ses_sse(data, x1) < ses_sse(data, x2) {
+            upper = x2;
+        } else {
+            lower = x1;
+        }
+    }
+    (lower + upper) / 2.0
+}
+
+#[wasm_bindgen]
+impl Benchmark {
+    // Classical Theta method (SES with half the linear trend slope as drift). The series is
+    // seasonally adjusted by multiplicative decomposition when seasonal is "multiplicative",
+    // or for "auto" when the lag-period autocorrelation is significant; "none" skips it
+    pub fn calculate_theta(&mut self, horizon: usize, level: f64, seasonal: String) -> Vec<f64> {
+        let data = linear_interpolation(&self.get_data());
+        let n = data.len();
+        let m = self.get_period().max(1) as usize;
+        if n < 3 {
+            panic!("Theta method needs at least 3 observations");
+        }
+        let positive = data.iter().all(|x| *x > 0.0);
+        let adjust = match seasonal.as_str() {
+            "none" => false,
+            "multiplicative" if m > 1 && n >= 2 * m && positive => true,
+            "multiplicative" => panic!("Multiplicative seasonal adjustment needs positive data and two full seasons"),
+            "auto" => m > 1 && n >= 2 * m && positive && is_seasonal(&data, m),
+            _ => panic!("Unknown seasonal adjustment: {}", seasonal),
+        };
+
+        // Seasonal component from the multiplicative decomposition
+        let (component, indices) = if adjust {
+            let mut decomposition = Decomposition::new(data.clone(), self.get_data_header(), self.get_time(), self.get_time_header(), m as i32);
+            let centered_ma = decomposition.calculate_centered_moving_average();
+            let component = decomposition.calculate_multiplicative_seasonal_component(centered_ma);
+            (component, decomposition.get_seasonal_indices())
+        } else {
+            (vec![1.0; n], vec![1.0; m])
+        };
+        let adjusted: Vec<f64> = data.iter().zip(component.iter()).map(|(y, s)| y / s).collect();
+
+        // Slope of the linear trend (theta = 0 line)
+        let t_mean = (n as f64 - 1.0) / 2.0;
+        let y_mean = adjusted.iter().sum::<f64>() / n as f64;
+        let sxy: f64 = adjusted.iter().enumerate().map(|(t, y)| (t as f64 - t_mean) * (y - y_mean)).sum();
+        let sxx: f64 = (0..n).map(|t| (t as f64 - t_mean).powi(2)).sum();
+        let slope = sxy / sxx;
+
+        // Theta = 2 line extrapolated by SES, averaged with the trend line
+        let alpha = optimal_alpha(&adjusted);
+        let (ses_fitted, last_level) = ses(&adjusted, alpha);
+        let drift = |h: f64, t: usize| slope / 2.0 * (h - 1.0 + (1.0 - (1.0 - alpha).powi(t as i32)) / alpha);
+        let adjusted_fitted: Vec<f64> = (0..n).map(|t| ses_fitted[t] + drift(1.0, t)).collect();
+        let sd = residual_sd(&adjusted, &adjusted_fitted, 2);
+        let fitted: Vec<f64> = adjusted_fitted.iter().zip(component.iter()).map(|(f, s)| f * s).collect();
+        let index = |h: usize| indices[(n + h - 1) % m];
+        let forecast: Vec<f64> = (1..=horizon).map(|h| (last_level + drift(h as f64, n)) * index(h)).collect();
+        let forecast_se: Vec<f64> = (1..=horizon).map(|h| sd * (1.0 + alpha * alpha * (h as f64 - 1.0)).sqrt() * index(h)).collect();
+
+        self.set_alpha(alpha);
+        self.store_forecast(fitted, forecast.clone(), forecast_se, level);
+        forecast
+    }
+}
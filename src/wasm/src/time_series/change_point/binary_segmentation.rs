@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+use crate::ChangePoint;
+use crate::time_series::change_point::segment_cost::SegmentCost;
+
+// Best split of data[start..end], returned as (split, cost reduction)
+fn best_split(segment_cost: &SegmentCost, start: usize, end: usize, min_len: usize) -> Option<(usize, f64)> {
+    if end - start < 2 * min_len {
+        return None;
+    }
+    let full = segment_cost.cost(start, end);
+    let mut best: Option<(usize, f64)> = None;
+    for s in start + min_len..=end - min_len {
+        let gain = full - segment_cost.cost(start, s) - segment_cost.cost(s, end);
+        if best.is_none() || gain > best.unwrap().1 {
+            best = Some((s, gain));
+        }
+    }
+    best
+}
+
+#[wasm_bindgen]
+impl ChangePoint{
+    // Binary segmentation, splitting the segment with the largest cost reduction first
+    pub fn calculate_binary_segmentation(&mut self, change_type: String, cost: String, penalty: String, penalty_value: f64, min_segment_length: usize, max_change_points: usize) -> Vec<usize>{
+        let data: Vec<f64> = self.get_data();
+        let n = data.len();
+        let min_len = min_segment_length.max(1);
+        let segment_cost = SegmentCost::new(&data, &change_type, &cost, penalty == "mbic");
+        let beta = segment_cost.penalty(&penalty, penalty_value);
+
+        let mut segments: Vec<(usize, usize)> = vec![(0, n)];
+        let mut change_point: Vec<usize> = Vec::new();
+        while change_point.len() < max_change_points {
+            let mut best: Option<(usize, usize, f64)> = None; // (segment, split, gain)
+            for (i, (start, end)) in segments.iter().enumerate(){
+                if let Some((split, gain)) = best_split(&segment_cost, *start, *end, min_len) {
+                    if best.is_none() || gain > best.unwrap().2 {
+                        best = Some((i, split, gain));
+                    }
+                }
+            }
+            match best {
+                Some((i, split, gain)) if gain > beta => {
+                    let (start, end) = segments.remove(i);
+                    segments.push((start, split));
+                    segments.push((split, end));
+                    change_point.push(split);
+                },
+                _ => break,
+            }
+        }
+        change_point.sort();
+
+        self.set_penalty_value(beta);
+        self.calculate_segment_summary(change_point.clone());
+        change_point
+    }
+}
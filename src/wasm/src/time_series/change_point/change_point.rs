@@ -0,0 +1,97 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct ChangePoint {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    penalty_value: f64,
+    change_point: Vec<usize>, // First index of every segment after the first
+    change_time: Vec<String>,
+    segment_start: Vec<usize>,
+    segment_end: Vec<usize>, // Last index of every segment
+    segment_mean: Vec<f64>,
+    segment_variance: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl ChangePoint{
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String) -> ChangePoint{
+        ChangePoint{
+            data,
+            data_header,
+            time,
+            time_header,
+            penalty_value: 0.0,
+            change_point: Vec::new(),
+            change_time: Vec::new(),
+            segment_start: Vec::new(),
+            segment_end: Vec::new(),
+            segment_mean: Vec::new(),
+            segment_variance: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64>{
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String{
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String>{
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String{
+        self.time_header.clone()
+    }
+    pub fn get_penalty_value(&self) -> f64{
+        self.penalty_value
+    }
+    pub fn get_change_point(&self) -> Vec<usize>{
+        self.change_point.clone()
+    }
+    pub fn get_change_time(&self) -> Vec<String>{
+        self.change_time.clone()
+    }
+    pub fn get_segment_start(&self) -> Vec<usize>{
+        self.segment_start.clone()
+    }
+    pub fn get_segment_end(&self) -> Vec<usize>{
+        self.segment_end.clone()
+    }
+    pub fn get_segment_mean(&self) -> Vec<f64>{
+        self.segment_mean.clone()
+    }
+    pub fn get_segment_variance(&self) -> Vec<f64>{
+        self.segment_variance.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>){
+        self.data = data;
+    }
+    pub fn set_penalty_value(&mut self, penalty_value: f64){
+        self.penalty_value = penalty_value;
+    }
+    pub fn set_change_point(&mut self, change_point: Vec<usize>){
+        self.change_point = change_point;
+    }
+    pub fn set_change_time(&mut self, change_time: Vec<String>){
+        self.change_time = change_time;
+    }
+    pub fn set_segment_start(&mut self, segment_start: Vec<usize>){
+        self.segment_start = segment_start;
+    }
+    pub fn set_segment_end(&mut self, segment_end: Vec<usize>){
+        self.segment_end = segment_end;
+    }
+    pub fn set_segment_mean(&mut self, segment_mean: Vec<f64>){
+        self.segment_mean = segment_mean;
+    }
+    pub fn set_segment_variance(&mut self, segment_variance: Vec<f64>){
+        self.segment_variance = segment_variance;
+    }
+}
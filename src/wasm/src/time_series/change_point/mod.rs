@@ -0,0 +1,7 @@
+pub mod change_point;
+pub mod segment_cost;
+pub mod pelt;
+pub mod binary_segmentation;
+pub mod segment_summary;
+
+pub use change_point::ChangePoint;
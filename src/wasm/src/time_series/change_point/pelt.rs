@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+use crate::ChangePoint;
+use crate::time_series::change_point::segment_cost::SegmentCost;
+
+#[wasm_bindgen]
+impl ChangePoint{
+    // Pruned Exact Linear Time (PELT) search for multiple change points
+    pub fn calculate_pelt(&mut self, change_type: String, cost: String, penalty: String, penalty_value: f64, min_segment_length: usize) -> Vec<usize>{
+        let data: Vec<f64> = self.get_data();
+        let n = data.len();
+        let min_len = min_segment_length.max(1);
+        let segment_cost = SegmentCost::new(&data, &change_type, &cost, penalty == "mbic");
+        let beta = segment_cost.penalty(&penalty, penalty_value);
+
+        // optimal[t] is the minimum penalised cost of data[0..t], last[t] its final change point
+        let mut optimal: Vec<f64> = vec![f64::INFINITY; n + 1];
+        let mut last: Vec<usize> = vec![0; n + 1];
+        optimal[0] = -beta;
+        let mut candidates: Vec<usize> = vec![0];
+        for t in min_len..=n{
+            let mut costs: Vec<(usize, f64)> = Vec::new();
+            for s in candidates.iter(){
+                if t - s >= min_len {
+                    costs.push((*s, optimal[*s] + segment_cost.cost(*s, t)));
+                }
+            }
+            for (s, c) in costs.iter(){
+                if c + beta < optimal[t] {
+                    optimal[t] = c + beta;
+                    last[t] = *s;
+                }
+            }
+
+            // Prune the start points that can never be optimal again
+            let best = optimal[t];
+            candidates.retain(|s| match costs.iter().find(|c| c.0 == *s) {
+                Some((_, c)) => *c <= best,
+                None => true,
+            });
+            if t + 1 >= 2 * min_len {
+                candidates.push(t + 1 - min_len);
+            }
+        }
+
+        // Trace back the change points
+        let mut change_point: Vec<usize> = Vec::new();
+        let mut t = n;
+        while last[t] > 0 {
+            change_point.push(last[t]);
+            t = last[t];
+        }
+        change_point.reverse();
+
+        self.set_penalty_value(beta);
+        self.calculate_segment_summary(change_point.clone());
+        change_point
+    }
+}
@@ -0,0 +1,130 @@
+use std::f64::consts::PI;
+
+// Cost of a segment as twice the negative log-likelihood, computed from cumulative sums
+pub struct SegmentCost {
+    change_type: String, // mean, variance or meanvar
+    cost: String,        // normal, poisson or nonparametric
+    mbic: bool,
+    n: usize,
+    mean: f64,
+    scale: f64,
+    sum: Vec<f64>,
+    sum_square: Vec<f64>,
+    quantile_count: Vec<Vec<f64>>,
+}
+
+impl SegmentCost {
+    pub fn new(data: &[f64], change_type: &str, cost: &str, mbic: bool) -> SegmentCost {
+        let n = data.len();
+        match (cost, change_type) {
+            ("normal", "mean") | ("normal", "variance") | ("normal", "meanvar") => {},
+            ("poisson", _) | ("nonparametric", _) => {},
+            _ => panic!("Unknown cost: {} {}", cost, change_type),
+        }
+        if cost == "poisson" && data.iter().any(|x| *x < 0.0) {
+            panic!("Poisson cost requires non-negative data");
+        }
+
+        let mean = data.iter().sum::<f64>() / n as f64;
+        let mut sum: Vec<f64> = vec![0.0];
+        let mut sum_square: Vec<f64> = vec![0.0];
+        for x in data.iter() {
+            sum.push(sum[sum.len() - 1] + x);
+            sum_square.push(sum_square[sum_square.len() - 1] + x * x);
+        }
+
+        // Noise scale for the change in mean, robust to the changes themselves
+        let mut scale = 1.0;
+        if change_type == "mean" && cost == "normal" && n > 2 {
+            let mut diff: Vec<f64> = (1..n).map(|i| (data[i] - data[i - 1]).abs()).collect();
+            diff.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let mad = 1.4826 * diff[diff.len() / 2] / 2.0_f64.sqrt();
+            let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n as f64 - 1.0);
+            scale = if mad > 0.0 { mad.powi(2) } else if variance > 0.0 { variance } else { 1.0 };
+        }
+
+        // Empirical distribution counts at K quantiles for the nonparametric cost
+        let mut quantile_count: Vec<Vec<f64>> = Vec::new();
+        if cost == "nonparametric" {
+            let k = (4.0 * (n as f64).ln()).ceil().max(1.0) as usize;
+            let mut sorted: Vec<f64> = data.to_vec();
+            sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let base = 2.0 * n as f64 - 1.0;
+            for q in 1..=k {
+                let p = 1.0 / (1.0 + base.powf(1.0 - (2.0 * q as f64 - 1.0) / k as f64));
+                let position = ((n as f64 - 1.0) * p).round() as usize;
+                let threshold = sorted[position.min(n - 1)];
+                let mut count: Vec<f64> = vec![0.0];
+                for x in data.iter() {
+                    let value = if *x < threshold { 1.0 } else if *x == threshold { 0.5 } else { 0.0 };
+                    count.push(count[count.len() - 1] + value);
+                }
+                quantile_count.push(count);
+            }
+        }
+
+        SegmentCost {
+            change_type: change_type.to_string(),
+            cost: cost.to_string(),
+            mbic,
+            n,
+            mean,
+            scale,
+            sum,
+            sum_square,
+            quantile_count,
+        }
+    }
+
+    // Number of parameters that change at each change point
+    pub fn parameters(&self) -> f64 {
+        if self.cost == "normal" && self.change_type == "meanvar" { 2.0 } else { 1.0 }
+    }
+
+    // Cost of the segment data[start..end]
+    pub fn cost(&self, start: usize, end: usize) -> f64 {
+        let len = (end - start) as f64;
+        let s1 = self.sum[end] - self.sum[start];
+        let s2 = self.sum_square[end] - self.sum_square[start];
+        let value = match self.cost.as_str() {
+            "normal" => match self.change_type.as_str() {
+                "mean" => (s2 - s1 * s1 / len).max(0.0) / self.scale,
+                "variance" => {
+                    let ss = s2 - 2.0 * self.mean * s1 + len * self.mean * self.mean;
+                    let variance = (ss / len).max(1e-10);
+                    len * ((2.0 * PI).ln() + variance.ln() + 1.0)
+                },
+                _ => {
+                    let variance = ((s2 - s1 * s1 / len) / len).max(1e-10);
+                    len * ((2.0 * PI).ln() + variance.ln() + 1.0)
+                },
+            },
+            "poisson" => {
+                if s1 > 0.0 { 2.0 * (s1 - s1 * (s1 / len).ln()) } else { 0.0 }
+            },
+            _ => {
+                let k = self.quantile_count.len() as f64;
+                let mut entropy = 0.0;
+                for count in self.quantile_count.iter() {
+                    let f = (count[end] - count[start]) / len;
+                    if f > 0.0 && f < 1.0 {
+                        entropy -= len * (f * f.ln() + (1.0 - f) * (1.0 - f).ln());
+                    }
+                }
+                2.0 * (2.0 * self.n as f64 - 1.0).ln() / k * entropy
+            },
+        };
+        if self.mbic { value + len.ln() } else { value }
+    }
+
+    // Penalty per change point
+    pub fn penalty(&self, penalty: &str, penalty_value: f64) -> f64 {
+        let n = self.n as f64;
+        match penalty {
+            "bic" => self.parameters() * n.ln(),
+            "mbic" => 3.0 * self.parameters() * n.ln(),
+            "manual" => penalty_value,
+            _ => panic!("Unknown penalty: {}", penalty),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+use crate::ChangePoint;
+
+#[wasm_bindgen]
+impl ChangePoint{
+    // Boundaries, means and variances of the segments between change points
+    pub fn calculate_segment_summary(&mut self, change_point: Vec<usize>){
+        let data: Vec<f64> = self.get_data();
+        let time: Vec<String> = self.get_time();
+        let mut segment_start: Vec<usize> = vec![0];
+        segment_start.extend(change_point.iter());
+        let mut segment_end: Vec<usize> = change_point.iter().map(|c| c - 1).collect();
+        segment_end.push(data.len() - 1);
+
+        let mut segment_mean: Vec<f64> = Vec::new();
+        let mut segment_variance: Vec<f64> = Vec::new();
+        for (start, end) in segment_start.iter().zip(segment_end.iter()){
+            let segment = &data[*start..*end + 1];
+            let len = segment.len() as f64;
+            let mean = segment.iter().sum::<f64>() / len;
+            let variance = if segment.len() > 1 {
+                segment.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1.0)
+            } else {
+                0.0
+            };
+            segment_mean.push(mean);
+            segment_variance.push(variance);
+        }
+
+        self.set_change_time(change_point.iter().map(|c| if *c < time.len() { time[*c].clone() } else { (c + 1).to_string() }).collect());
+        self.set_change_point(change_point);
+        self.set_segment_start(segment_start);
+        self.set_segment_end(segment_end);
+        self.set_segment_mean(segment_mean);
+        self.set_segment_variance(segment_variance);
+    }
+}
@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+use crate::CycleFilter;
+use std::f64::consts::PI;
+
+// Ideal band-pass weights B_0..B_k for cycles between low and high periods
+pub fn ideal_band_pass_weights(low: f64, high: f64, k: usize) -> Vec<f64> {
+    let a = 2.0 * PI / high;
+    let b = 2.0 * PI / low;
+    let mut weights: Vec<f64> = vec![(b - a) / PI];
+    for j in 1..=k {
+        weights.push(((j as f64 * b).sin() - (j as f64 * a).sin()) / (PI * j as f64));
+    }
+    weights
+}
+
+#[wasm_bindgen]
+impl CycleFilter{
+    // Default band (in periods) of business cycles: 1.5 to 8 years
+    pub fn default_band(&self) -> Vec<f64>{
+        let period = if self.get_period() > 0 { self.get_period() as f64 } else { 4.0 };
+        vec![(1.5 * period).max(2.0), 8.0 * period]
+    }
+
+    // Baxter-King Filter, the first and last k values are lost and left as NaN
+    pub fn calculate_baxter_king_filter(&mut self, low: f64, high: f64, k: i32) -> Vec<f64>{
+        let data: Vec<f64> = self.get_data();
+        let n = data.len();
+        let band = self.default_band();
+        let low = if low > 0.0 { low } else { band[0] };
+        let high = if high > 0.0 { high } else { band[1] };
+        let k = if k > 0 { k as usize } else { 3 * self.get_period().max(1) as usize };
+
+        // Constrain the weights to sum to zero so the filter removes a stochastic trend
+        let mut weights: Vec<f64> = ideal_band_pass_weights(low, high, k);
+        let total: f64 = weights[0] + 2.0 * weights[1..].iter().sum::<f64>();
+        let theta = total / (2 * k + 1) as f64;
+        for w in weights.iter_mut(){
+            *w -= theta;
+        }
+
+        let mut trend_component: Vec<f64> = vec![f64::NAN; n];
+        let mut cycle_component: Vec<f64> = vec![f64::NAN; n];
+        if n > 2 * k {
+            for i in k..n - k{
+                let mut cycle = weights[0] * data[i];
+                for j in 1..=k{
+                    cycle += weights[j] * (data[i - j] + data[i + j]);
+                }
+                cycle_component[i] = cycle;
+                trend_component[i] = data[i] - cycle;
+            }
+        }
+
+        self.set_trend_component(trend_component);
+        self.set_cycle_component(cycle_component.clone());
+        cycle_component
+    }
+}
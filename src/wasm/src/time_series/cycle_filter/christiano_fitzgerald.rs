@@ -0,0 +1,51 @@
+use wasm_bindgen::prelude::*;
+use crate::CycleFilter;
+use crate::time_series::cycle_filter::baxter_king::ideal_band_pass_weights;
+
+#[wasm_bindgen]
+impl CycleFilter{
+    // Christiano-Fitzgerald asymmetric (random walk) band-pass filter
+    pub fn calculate_christiano_fitzgerald_filter(&mut self, low: f64, high: f64, drift: bool) -> Vec<f64>{
+        let data: Vec<f64> = self.get_data();
+        let n = data.len();
+        let band = self.default_band();
+        let low = if low > 0.0 { low } else { band[0] };
+        let high = if high > 0.0 { high } else { band[1] };
+        if n < 2 {
+            self.set_trend_component(data.clone());
+            self.set_cycle_component(vec![0.0; n]);
+            return vec![0.0; n];
+        }
+
+        // Remove the drift of the random walk before filtering
+        let mut x: Vec<f64> = data.clone();
+        if drift {
+            let slope = (data[n - 1] - data[0]) / (n - 1) as f64;
+            for (i, value) in x.iter_mut().enumerate(){
+                *value -= slope * i as f64;
+            }
+        }
+
+        // The weights of the end points absorb the truncated tails of the ideal filter
+        let b: Vec<f64> = ideal_band_pass_weights(low, high, n);
+        let mut cycle_component: Vec<f64> = Vec::new();
+        for t in 0..n{
+            let mut cycle = b[0] * x[t];
+            for j in 1..n - 1 - t{
+                cycle += b[j] * x[t + j];
+            }
+            for j in 1..t{
+                cycle += b[j] * x[t - j];
+            }
+            let b_tilde_end = -b[0] / 2.0 - b[1..(n - 1 - t).max(1)].iter().sum::<f64>();
+            let b_tilde_start = -b[0] / 2.0 - b[1..t.max(1)].iter().sum::<f64>();
+            cycle += b_tilde_end * x[n - 1] + b_tilde_start * x[0];
+            cycle_component.push(cycle);
+        }
+        let trend_component: Vec<f64> = data.iter().zip(cycle_component.iter()).map(|(y, c)| y - c).collect();
+
+        self.set_trend_component(trend_component);
+        self.set_cycle_component(cycle_component.clone());
+        cycle_component
+    }
+}
@@ -0,0 +1,70 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct CycleFilter {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    trend_component: Vec<f64>,
+    cycle_component: Vec<f64>,
+    period: i32, // Observations per year: 1 annual, 4 quarterly, 12 monthly
+    lambda: f64,
+}
+
+#[wasm_bindgen]
+impl CycleFilter{
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String, period: i32) -> CycleFilter{
+        CycleFilter{
+            data,
+            data_header,
+            time,
+            time_header,
+            trend_component: Vec::new(),
+            cycle_component: Vec::new(),
+            period,
+            lambda: 0.0,
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64>{
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String{
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String>{
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String{
+        self.time_header.clone()
+    }
+    pub fn get_trend_component(&self) -> Vec<f64>{
+        self.trend_component.clone()
+    }
+    pub fn get_cycle_component(&self) -> Vec<f64>{
+        self.cycle_component.clone()
+    }
+    pub fn get_period(&self) -> i32{
+        self.period
+    }
+    pub fn get_lambda(&self) -> f64{
+        self.lambda
+    }
+
+    // Setters
+    pub fn set_trend_component(&mut self, trend_component: Vec<f64>){
+        self.trend_component = trend_component;
+    }
+    pub fn set_cycle_component(&mut self, cycle_component: Vec<f64>){
+        self.cycle_component = cycle_component;
+    }
+    pub fn set_period(&mut self, period: i32){
+        self.period = period;
+    }
+    pub fn set_lambda(&mut self, lambda: f64){
+        self.lambda = lambda;
+    }
+}
@@ -0,0 +1,101 @@
+use wasm_bindgen::prelude::*;
+use crate::CycleFilter;
+
+// Solve the pentadiagonal system (I + lambda * D'D) trend = data by banded Cholesky,
+// where D is the second difference operator
+pub fn hp_trend(data: &[f64], lambda: f64) -> Vec<f64> {
+    let n = data.len();
+    if n < 3 {
+        return data.to_vec();
+    }
+
+    // band[i][k] holds A[i][i - k] for k = 0, 1, 2
+    let mut band: Vec<[f64; 3]> = vec![[0.0; 3]; n];
+    for i in 0..n - 2 {
+        // Row i of D is (1, -2, 1) on columns i, i + 1, i + 2
+        let d = [1.0, -2.0, 1.0];
+        for a in 0..3 {
+            for b in 0..=a {
+                band[i + a][a - b] += lambda * d[a] * d[b];
+            }
+        }
+    }
+    for row in band.iter_mut() {
+        row[0] += 1.0;
+    }
+
+    // Cholesky factor with the same band layout
+    let mut l: Vec<[f64; 3]> = vec![[0.0; 3]; n];
+    for i in 0..n {
+        for k in (0..=2.min(i)).rev() {
+            let j = i - k;
+            let mut sum = band[i][k];
+            for m in i.saturating_sub(2)..j {
+                sum -= l[i][i - m] * l[j][j - m];
+            }
+            if k == 0 {
+                l[i][0] = sum.sqrt();
+            } else {
+                l[i][k] = sum / l[j][0];
+            }
+        }
+    }
+
+    // Forward and backward substitution
+    let mut z: Vec<f64> = vec![0.0; n];
+    for i in 0..n {
+        let mut sum = data[i];
+        for k in 1..=2.min(i) {
+            sum -= l[i][k] * z[i - k];
+        }
+        z[i] = sum / l[i][0];
+    }
+    let mut trend: Vec<f64> = vec![0.0; n];
+    for i in (0..n).rev() {
+        let mut sum = z[i];
+        for k in 1..=2.min(n - 1 - i) {
+            sum -= l[i + k][k] * trend[i + k];
+        }
+        trend[i] = sum / l[i][0];
+    }
+    trend
+}
+
+#[wasm_bindgen]
+impl CycleFilter{
+    // Default smoothing parameter, 1600 for quarterly data scaled by the square of the frequency
+    pub fn default_hp_lambda(&self) -> f64{
+        let period = if self.get_period() > 0 { self.get_period() } else { 4 };
+        1600.0 * (period as f64 / 4.0).powi(2)
+    }
+
+    // Hodrick-Prescott Filter
+    pub fn calculate_hp_filter(&mut self, lambda: f64) -> Vec<f64>{
+        let data: Vec<f64> = self.get_data();
+        let lambda = if lambda > 0.0 { lambda } else { self.default_hp_lambda() };
+        let trend_component: Vec<f64> = hp_trend(&data, lambda);
+        let cycle_component: Vec<f64> = data.iter().zip(trend_component.iter()).map(|(y, t)| y - t).collect();
+
+        self.set_lambda(lambda);
+        self.set_trend_component(trend_component);
+        self.set_cycle_component(cycle_component.clone());
+        cycle_component
+    }
+
+    // One-Sided Hodrick-Prescott Filter, the trend at t only uses data up to t
+    pub fn calculate_one_sided_hp_filter(&mut self, lambda: f64) -> Vec<f64>{
+        let data: Vec<f64> = self.get_data();
+        let lambda = if lambda > 0.0 { lambda } else { self.default_hp_lambda() };
+        let mut trend_component: Vec<f64> = Vec::new();
+        for i in 0..data.len(){
+            let trend: Vec<f64> = hp_trend(&data[0..i + 1], lambda);
+            trend_component.push(trend[i]);
+        }
+        let cycle_component: Vec<f64> = data.iter().zip(trend_component.iter()).map(|(y, t)| y - t).collect();
+
+        self.set_lambda(lambda);
+        self.set_trend_component(trend_component);
+        self.set_cycle_component(cycle_component.clone());
+        cycle_component
+    }
+}
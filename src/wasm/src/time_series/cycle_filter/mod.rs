@@ -0,0 +1,6 @@
+pub mod cycle_filter;
+pub mod hodrick_prescott;
+pub mod baxter_king;
+pub mod christiano_fitzgerald;
+
+pub use cycle_filter::CycleFilter;
@@ -35,7 +35,7 @@ impl Decomposition{
         for i in 0..data.len(){
             forecast.push(trend_component[i] + seasonal_component[i]);
         }
-        forecast
+        self.back_transform_fitted(forecast)
     }
 }
 
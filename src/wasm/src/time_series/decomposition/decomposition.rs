@@ -1,4 +1,5 @@
 use wasm_bindgen::prelude::*;
+use crate::TimeIndex;
 
 #[wasm_bindgen]
 pub struct Decomposition {
@@ -12,6 +13,9 @@ pub struct Decomposition {
     seasonal_indices: Vec<f64>,
     period: i32,
     trend_equation: String,
+    transformation: String, // "none", "box_cox" or "yeo_johnson"; data then holds the transformed series
+    lambda: f64,
+    original_data: Vec<f64>,
 }
 
 #[wasm_bindgen]
@@ -29,6 +33,9 @@ impl Decomposition{
             seasonal_indices: Vec::new(),
             period,
             trend_equation: String::new(),
+            transformation: "none".to_string(),
+            lambda: 1.0,
+            original_data: Vec::new(),
         }
     }
 
@@ -45,6 +52,9 @@ impl Decomposition{
     pub fn get_time_header(&self) -> String{
         self.time_header.clone()
     }
+    pub fn get_time_index(&self) -> TimeIndex{
+        TimeIndex::new(self.time.clone(), self.time_header.clone())
+    }
     pub fn get_seasonal_component(&self) -> Vec<f64>{
         self.seasonal_component.clone()
     }
@@ -63,8 +73,24 @@ impl Decomposition{
     pub fn get_trend_equation(&self) -> String{
         self.trend_equation.clone()
     }
+    pub fn get_transformation(&self) -> String{
+        self.transformation.clone()
+    }
+    pub fn get_lambda(&self) -> f64{
+        self.lambda
+    }
+    pub fn get_original_data(&self) -> Vec<f64>{
+        if self.transformation == "none" {
+            self.data.clone()
+        } else {
+            self.original_data.clone()
+        }
+    }
 
     // Setters
+    pub fn set_data(&mut self, data: Vec<f64>){
+        self.data = data;
+    }
     pub fn set_seasonal_component(&mut self, seasonal_component: Vec<f64>){
         self.seasonal_component = seasonal_component.clone();
     }
@@ -80,4 +106,13 @@ impl Decomposition{
     pub fn set_trend_equation(&mut self, trend_equation: String){
         self.trend_equation = trend_equation.clone();
     }
+    pub fn set_transformation(&mut self, transformation: String){
+        self.transformation = transformation;
+    }
+    pub fn set_lambda(&mut self, lambda: f64){
+        self.lambda = lambda;
+    }
+    pub fn set_original_data(&mut self, original_data: Vec<f64>){
+        self.original_data = original_data;
+    }
 }
\ No newline at end of file
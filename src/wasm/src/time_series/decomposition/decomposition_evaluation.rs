@@ -7,11 +7,12 @@ use crate::time_series::evaluation::basic_evaluation::*;
 #[wasm_bindgen]
 impl Decomposition {
     pub fn decomposition_evaluation(&self, forecast: Vec<f64>) -> JsValue {
-        let mse = mse(self.get_data(), forecast.clone()) as f64;
-        let rmse = rmse(self.get_data(), forecast.clone()) as f64;
-        let mae = mae(self.get_data(), forecast.clone()) as f64;
-        let mpe = mpe(self.get_data(), forecast.clone()) as f64;
-        let mape = mape(self.get_data(), forecast.clone()) as f64;
+        let data = self.get_original_data();
+        let mse = mse(data.clone(), forecast.clone());
+        let rmse = rmse(data.clone(), forecast.clone());
+        let mae = mae(data.clone(), forecast.clone());
+        let mpe = mpe(data.clone(), forecast.clone());
+        let mape = mape(data.clone(), forecast.clone());
         
         let results = Object::new();
         Reflect::set(&results, &"MSE".into(), &mse.into()).unwrap();
@@ -0,0 +1,34 @@
+use wasm_bindgen::prelude::*;
+use crate::Decomposition;
+use crate::time_series::transformation::power_transform::*;
+use crate::time_series::transformation::lambda_estimation::estimate_lambda;
+
+#[wasm_bindgen]
+impl Decomposition{
+    // Decompose the Box-Cox or Yeo-Johnson transformed series from now on; lambda_method is
+    // "guerrero", "likelihood" or "manual" (use the given lambda). Returns the lambda used
+    pub fn transform_data(&mut self, method: String, lambda_method: String, lambda: f64) -> f64{
+        let data = self.get_original_data();
+        if method == "box_cox" && data.iter().any(|x| *x <= 0.0) {
+            panic!("Box-Cox transformation needs positive data");
+        }
+        let lambda = estimate_lambda(&data, &method, &lambda_method, lambda, self.get_period().max(1) as usize);
+        let transformed: Vec<f64> = data.iter().map(|x| transform(&method, *x, lambda)).collect();
+        self.set_original_data(data);
+        self.set_transformation(method);
+        self.set_lambda(lambda);
+        self.set_data(transformed);
+        lambda
+    }
+
+    // Fitted values back on the original scale, bias adjusted with the residual variance
+    pub fn back_transform_fitted(&self, fitted: Vec<f64>) -> Vec<f64>{
+        if self.get_transformation() == "none" {
+            return fitted;
+        }
+        let data = self.get_data();
+        let residual: Vec<f64> = data.iter().zip(fitted.iter()).map(|(x, f)| x - f).filter(|r| r.is_finite()).collect();
+        let variance = residual.iter().map(|r| r * r).sum::<f64>() / residual.len().max(1) as f64;
+        back_transform(&self.get_transformation(), self.get_lambda(), &fitted, &vec![variance; fitted.len()])
+    }
+}
@@ -3,5 +3,6 @@ pub mod centered_moving_average;
 pub mod multiplicative;
 pub mod additive;
 pub mod decomposition_evaluation;
+pub mod decomposition_transformation;
 
 pub use decomposition::Decomposition;
\ No newline at end of file
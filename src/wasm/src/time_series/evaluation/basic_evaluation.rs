@@ -1,5 +1,17 @@
+// Pairs of actual and forecast values where neither is missing (NaN)
+pub fn observed_pairs(data: Vec<f64>, forecast: Vec<f64>) -> (Vec<f64>, Vec<f64>){
+    data.iter().zip(forecast.iter()).filter(|(y, f)| !y.is_nan() && !f.is_nan()).map(|(y, f)| (*y, *f)).unzip()
+}
+
+// Pairs whose actual value is nonzero, the ones a percentage error is defined for
+pub fn nonzero_pairs(data: Vec<f64>, forecast: Vec<f64>) -> (Vec<f64>, Vec<f64>){
+    let (data, forecast) = observed_pairs(data, forecast);
+    data.iter().zip(forecast.iter()).filter(|(y, _)| **y != 0.0).map(|(y, f)| (*y, *f)).unzip()
+}
+
 // Mean Squared Error
 pub fn mse(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let (data, forecast) = observed_pairs(data, forecast);
     let mut sum: f64 = 0.0;
     for i in 0..data.len(){
         sum += (data[i] - forecast[i]).powi(2);
@@ -14,6 +26,7 @@ pub fn rmse(data: Vec<f64>, forecast: Vec<f64>) -> f64{
 
 // Mean Absolute Error
 pub fn mae(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let (data, forecast) = observed_pairs(data, forecast);
     let mut sum: f64 = 0.0;
     for i in 0..data.len(){
         sum += (data[i] - forecast[i]).abs();
@@ -21,8 +34,9 @@ pub fn mae(data: Vec<f64>, forecast: Vec<f64>) -> f64{
     sum / data.len() as f64
 }
 
-// Mean Percentage Error
+// Mean Percentage Error, over the periods with nonzero actual values
 pub fn mpe(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let (data, forecast) = nonzero_pairs(data, forecast);
     let mut sum: f64 = 0.0;
     for i in 0..data.len(){
         sum += (data[i] - forecast[i]) / data[i];
@@ -30,8 +44,9 @@ pub fn mpe(data: Vec<f64>, forecast: Vec<f64>) -> f64{
     sum / data.len() as f64
 }
 
-// Mean Absolute Percentage Error
+// Mean Absolute Percentage Error, over the periods with nonzero actual values
 pub fn mape(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let (data, forecast) = nonzero_pairs(data, forecast);
     let mut sum: f64 = 0.0;
     for i in 0..data.len(){
         sum += ((data[i] - forecast[i]) / data[i]).abs();
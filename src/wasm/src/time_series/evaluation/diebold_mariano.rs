@@ -0,0 +1,48 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect};
+use statrs::distribution::{ContinuousCDF, StudentsT};
+
+// Diebold-Mariano test of equal accuracy of two forecast error series for h-step forecasts,
+// with the Harvey, Leybourne and Newbold small-sample correction. Loss is "squared" or "absolute".
+// Returns (statistic, two-sided p-value, degrees of freedom); a negative statistic favours the first forecast
+pub fn diebold_mariano(error1: Vec<f64>, error2: Vec<f64>, horizon: usize, loss: &str) -> (f64, f64, f64){
+    let loss_fn = |e: f64| match loss {
+        "squared" => e * e,
+        "absolute" => e.abs(),
+        _ => panic!("Unknown loss: {}", loss),
+    };
+    let d: Vec<f64> = error1.iter().zip(error2.iter())
+        .filter(|(a, b)| !a.is_nan() && !b.is_nan())
+        .map(|(a, b)| loss_fn(*a) - loss_fn(*b))
+        .collect();
+    let n = d.len();
+    let horizon = horizon.max(1);
+    if n < 2 || horizon >= n {
+        panic!("Diebold-Mariano test needs more loss differentials than the horizon");
+    }
+    let mean = d.iter().sum::<f64>() / n as f64;
+    let autocovariance = |k: usize| (k..n).map(|t| (d[t] - mean) * (d[t - k] - mean)).sum::<f64>() / n as f64;
+    // Long-run variance from the first h - 1 autocovariances, falling back to the variance when not positive
+    let mut long_run = autocovariance(0) + 2.0 * (1..horizon).map(autocovariance).sum::<f64>();
+    if long_run <= 0.0 {
+        long_run = autocovariance(0);
+    }
+    let statistic = mean / (long_run / n as f64).sqrt();
+    let (nf, hf) = (n as f64, horizon as f64);
+    let correction = ((nf + 1.0 - 2.0 * hf + hf * (hf - 1.0) / nf) / nf).sqrt();
+    let statistic = statistic * correction;
+    let df = nf - 1.0;
+    let t_dist = StudentsT::new(0.0, 1.0, df).unwrap();
+    let p_value = 2.0 * (1.0 - t_dist.cdf(statistic.abs()));
+    (statistic, p_value, df)
+}
+
+#[wasm_bindgen]
+pub fn diebold_mariano_test(error1: Vec<f64>, error2: Vec<f64>, horizon: usize, loss: String) -> JsValue{
+    let (statistic, p_value, df) = diebold_mariano(error1, error2, horizon, &loss);
+    let results = Object::new();
+    Reflect::set(&results, &"DM".into(), &statistic.into()).unwrap();
+    Reflect::set(&results, &"df".into(), &df.into()).unwrap();
+    Reflect::set(&results, &"p-value".into(), &p_value.into()).unwrap();
+    JsValue::from(results)
+}
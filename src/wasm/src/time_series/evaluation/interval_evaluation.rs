@@ -0,0 +1,52 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect};
+use statrs::distribution::{Continuous, ContinuousCDF, Normal};
+
+// Actual values with their interval bounds, skipping missing values (NaN)
+fn observed_intervals(data: &[f64], lower: &[f64], upper: &[f64]) -> Vec<(f64, f64, f64)>{
+    data.iter().zip(lower.iter()).zip(upper.iter())
+        .map(|((y, l), u)| (*y, *l, *u))
+        .filter(|(y, l, u)| !y.is_nan() && !l.is_nan() && !u.is_nan())
+        .collect()
+}
+
+// Share of actual values inside their prediction interval
+pub fn coverage(data: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>) -> f64{
+    let points = observed_intervals(&data, &lower, &upper);
+    points.iter().filter(|(y, l, u)| y >= l && y <= u).count() as f64 / points.len() as f64
+}
+
+// Winkler score: interval width plus 2 / (1 - level) times the distance of a miss
+pub fn winkler_score(data: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>, level: f64) -> f64{
+    let points = observed_intervals(&data, &lower, &upper);
+    let penalty = 2.0 / (1.0 - level);
+    let scores: Vec<f64> = points.iter().map(|(y, l, u)| {
+        let miss = if y < l { l - y } else if y > u { y - u } else { 0.0 };
+        (u - l) + penalty * miss
+    }).collect();
+    scores.iter().sum::<f64>() / scores.len() as f64
+}
+
+// Continuous Ranked Probability Score of normal forecast distributions with the given means and standard deviations
+pub fn crps_normal(data: Vec<f64>, forecast: Vec<f64>, sd: Vec<f64>) -> f64{
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let points = observed_intervals(&data, &forecast, &sd);
+    let scores: Vec<f64> = points.iter().map(|(y, mean, sd)| {
+        let z = (y - mean) / sd;
+        sd * (z * (2.0 * normal.cdf(z) - 1.0) + 2.0 * normal.pdf(z) - 1.0 / std::f64::consts::PI.sqrt())
+    }).collect();
+    scores.iter().sum::<f64>() / scores.len() as f64
+}
+
+// Interval forecast accuracy table; CRPS treats each interval as a central normal interval at the given level
+#[wasm_bindgen]
+pub fn interval_accuracy(data: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>, level: f64) -> JsValue{
+    let z = Normal::new(0.0, 1.0).unwrap().inverse_cdf((1.0 + level) / 2.0);
+    let mean: Vec<f64> = lower.iter().zip(upper.iter()).map(|(l, u)| (l + u) / 2.0).collect();
+    let sd: Vec<f64> = lower.iter().zip(upper.iter()).map(|(l, u)| (u - l) / (2.0 * z)).collect();
+    let results = Object::new();
+    Reflect::set(&results, &"Coverage".into(), &coverage(data.clone(), lower.clone(), upper.clone()).into()).unwrap();
+    Reflect::set(&results, &"Winkler".into(), &winkler_score(data.clone(), lower, upper, level).into()).unwrap();
+    Reflect::set(&results, &"CRPS".into(), &crps_normal(data, mean, sd).into()).unwrap();
+    JsValue::from(results)
+}
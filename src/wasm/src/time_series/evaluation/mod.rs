@@ -1 +1,4 @@
-pub mod basic_evaluation;
\ No newline at end of file
+pub mod basic_evaluation;
+pub mod scaled_evaluation;
+pub mod interval_evaluation;
+pub mod diebold_mariano;
\ No newline at end of file
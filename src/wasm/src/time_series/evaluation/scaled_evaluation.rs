@@ -0,0 +1,77 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect};
+use crate::time_series::evaluation::basic_evaluation::*;
+
+// Mean of |y_t - y_(t-period)|^power over the training data, the in-sample (seasonal) naive error
+pub fn naive_scale(training: Vec<f64>, period: usize, power: i32) -> f64{
+    let period = period.max(1);
+    let errors: Vec<f64> = (period..training.len())
+        .map(|t| (training[t] - training[t - period]).abs().powi(power))
+        .filter(|e| !e.is_nan())
+        .collect();
+    if errors.is_empty() {
+        panic!("Training data must be longer than the period");
+    }
+    errors.iter().sum::<f64>() / errors.len() as f64
+}
+
+// Mean Absolute Scaled Error
+pub fn mase(data: Vec<f64>, forecast: Vec<f64>, training: Vec<f64>, period: usize) -> f64{
+    mae(data, forecast) / naive_scale(training, period, 1)
+}
+
+// Root Mean Squared Scaled Error
+pub fn rmsse(data: Vec<f64>, forecast: Vec<f64>, training: Vec<f64>, period: usize) -> f64{
+    (mse(data, forecast) / naive_scale(training, period, 2)).sqrt()
+}
+
+// Symmetric Mean Absolute Percentage Error, 2|y - f| / (|y| + |f|), skipping pairs that are both zero
+pub fn smape(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let (data, forecast) = observed_pairs(data, forecast);
+    let terms: Vec<f64> = data.iter().zip(forecast.iter())
+        .filter(|(y, f)| y.abs() + f.abs() > 0.0)
+        .map(|(y, f)| 2.0 * (y - f).abs() / (y.abs() + f.abs()))
+        .collect();
+    terms.iter().sum::<f64>() / terms.len() as f64
+}
+
+// Theil's U1, bounded between 0 (perfect) and 1
+pub fn theil_u1(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let (data, forecast) = observed_pairs(data, forecast);
+    let n = data.len() as f64;
+    let rmse = (data.iter().zip(forecast.iter()).map(|(y, f)| (y - f).powi(2)).sum::<f64>() / n).sqrt();
+    let data_norm = (data.iter().map(|y| y * y).sum::<f64>() / n).sqrt();
+    let forecast_norm = (forecast.iter().map(|f| f * f).sum::<f64>() / n).sqrt();
+    rmse / (data_norm + forecast_norm)
+}
+
+// Theil's U2, relative changes of the forecast against those of the naive forecast; below 1 beats naive
+pub fn theil_u2(data: Vec<f64>, forecast: Vec<f64>) -> f64{
+    let mut numerator = 0.0;
+    let mut denominator = 0.0;
+    for t in 1..data.len().min(forecast.len()) {
+        let (previous, y, f) = (data[t - 1], data[t], forecast[t]);
+        if previous.is_nan() || y.is_nan() || f.is_nan() || previous == 0.0 {
+            continue;
+        }
+        numerator += ((f - y) / previous).powi(2);
+        denominator += ((y - previous) / previous).powi(2);
+    }
+    (numerator / denominator).sqrt()
+}
+
+// Point forecast accuracy table; training is the data the model was fitted on
+#[wasm_bindgen]
+pub fn forecast_accuracy(data: Vec<f64>, forecast: Vec<f64>, training: Vec<f64>, period: usize) -> JsValue{
+    let results = Object::new();
+    Reflect::set(&results, &"MSE".into(), &mse(data.clone(), forecast.clone()).into()).unwrap();
+    Reflect::set(&results, &"RMSE".into(), &rmse(data.clone(), forecast.clone()).into()).unwrap();
+    Reflect::set(&results, &"MAE".into(), &mae(data.clone(), forecast.clone()).into()).unwrap();
+    Reflect::set(&results, &"MAPE".into(), &mape(data.clone(), forecast.clone()).into()).unwrap();
+    Reflect::set(&results, &"sMAPE".into(), &smape(data.clone(), forecast.clone()).into()).unwrap();
+    Reflect::set(&results, &"MASE".into(), &mase(data.clone(), forecast.clone(), training.clone(), period).into()).unwrap();
+    Reflect::set(&results, &"RMSSE".into(), &rmsse(data.clone(), forecast.clone(), training, period).into()).unwrap();
+    Reflect::set(&results, &"Theil U1".into(), &theil_u1(data.clone(), forecast.clone()).into()).unwrap();
+    Reflect::set(&results, &"Theil U2".into(), &theil_u2(data, forecast).into()).unwrap();
+    JsValue::from(results)
+}
@@ -0,0 +1,48 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, Benchmark, Decomposition, ForecastCombination, Smoothing, StructuralModel};
+use crate::time_series::forecast_combination::forecaster::*;
+
+impl ForecastCombination {
+    // Forecast the holdout from the model refitted without it, then the horizon from the whole series
+    pub fn add_member(&mut self, name: String, model: &dyn Forecaster) {
+        let series = model.series();
+        if series.len() != self.get_data().len() {
+            panic!("Series of {} does not match the combined series", name);
+        }
+        let (holdout_forecast, _) = if self.get_holdout() > 0 {
+            model.forecast_from(series.len() - self.get_holdout(), self.get_holdout())
+        } else {
+            (Vec::new(), Vec::new())
+        };
+        let (forecast, forecast_se) = model.forecast(self.get_horizon());
+        self.push_member(name, holdout_forecast, forecast, forecast_se);
+    }
+}
+
+#[wasm_bindgen]
+impl ForecastCombination {
+    pub fn add_arima(&mut self, name: String, model: &Arima) {
+        self.add_member(name, model);
+    }
+
+    pub fn add_smoothing(&mut self, name: String, model: &Smoothing, method: String, parameter: Vec<f64>, period: usize) {
+        self.add_member(name, &SmoothingForecaster { smoothing: model, method, parameter, period });
+    }
+
+    pub fn add_decomposition(&mut self, name: String, model: &Decomposition, method: String, trend: String) {
+        self.add_member(name, &DecompositionForecaster { decomposition: model, method, trend });
+    }
+
+    pub fn add_benchmark(&mut self, name: String, model: &Benchmark, method: String) {
+        self.add_member(name, &BenchmarkForecaster { benchmark: model, method });
+    }
+
+    pub fn add_structural(&mut self, name: String, model: &StructuralModel) {
+        self.add_member(name, model);
+    }
+
+    // Forecasts produced elsewhere, with the holdout forecasts made without the holdout
+    pub fn add_forecast(&mut self, name: String, holdout_forecast: Vec<f64>, forecast: Vec<f64>, forecast_se: Vec<f64>) {
+        self.push_member(name, holdout_forecast, forecast, forecast_se);
+    }
+}
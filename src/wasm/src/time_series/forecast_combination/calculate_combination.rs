@@ -0,0 +1,67 @@
+use wasm_bindgen::prelude::*;
+use crate::ForecastCombination;
+use crate::time_series::benchmark::prediction_interval::interval_multiplier;
+use crate::time_series::forecast_combination::combination_weight::*;
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let m = values.len() / 2;
+    if values.len().is_multiple_of(2) { (values[m - 1] + values[m]) / 2.0 } else { values[m] }
+}
+
+#[wasm_bindgen]
+impl ForecastCombination {
+    // Combine the model forecasts by "mean", "median", "inverse_mse" or "bates_granger"
+    // weights. The interval of a weighted combination uses the standard error of the
+    // weighted sum with the holdout error correlations; the median takes the median bounds
+    pub fn calculate_combination(&mut self, method: String, level: f64) -> Vec<f64> {
+        let data = self.get_data();
+        let holdout_forecast = self.get_holdout_forecast();
+        let forecast = self.get_member_forecast_values();
+        let forecast_se = self.get_member_se();
+        let origin = data.len() - self.get_holdout();
+        let errors: Vec<Vec<f64>> = holdout_forecast
+            .iter()
+            .map(|f| data[origin..].iter().zip(f.iter()).map(|(y, f)| y - f).collect())
+            .collect();
+        let weight = combination_weight(&errors, &method);
+        let z = interval_multiplier(level);
+        let k = forecast.len();
+
+        let (combined, lower, upper): (Vec<f64>, Vec<f64>, Vec<f64>) = if method == "median" {
+            let column = |h: usize, shift: f64| median(&mut (0..k).map(|i| forecast[i][h] + shift * z * forecast_se[i][h]).collect::<Vec<f64>>());
+            (
+                (0..self.get_horizon()).map(|h| column(h, 0.0)).collect(),
+                (0..self.get_horizon()).map(|h| column(h, -1.0)).collect(),
+                (0..self.get_horizon()).map(|h| column(h, 1.0)).collect(),
+            )
+        } else {
+            let correlation = error_correlation(&errors);
+            let mut combined = Vec::new();
+            let mut lower = Vec::new();
+            let mut upper = Vec::new();
+            for h in 0..self.get_horizon() {
+                let mean: f64 = (0..k).map(|i| weight[i] * forecast[i][h]).sum();
+                let mut variance = 0.0;
+                for i in 0..k {
+                    for j in 0..k {
+                        variance += weight[i] * weight[j] * correlation[(i, j)] * forecast_se[i][h] * forecast_se[j][h];
+                    }
+                }
+                let se = variance.max(0.0).sqrt();
+                combined.push(mean);
+                lower.push(mean - z * se);
+                upper.push(mean + z * se);
+            }
+            (combined, lower, upper)
+        };
+
+        let holdout_mse = errors.iter().map(|e| if e.is_empty() { f64::NAN } else { e.iter().map(|x| x * x).sum::<f64>() / e.len() as f64 }).collect();
+        self.set_holdout_mse(holdout_mse);
+        self.set_weight(weight);
+        self.set_lower_bound(lower);
+        self.set_upper_bound(upper);
+        self.set_forecast(combined.clone());
+        combined
+    }
+}
@@ -0,0 +1,53 @@
+use nalgebra::{DMatrix, DVector};
+
+// Uncentered covariance matrix of the holdout errors of the models
+pub fn error_covariance(errors: &[Vec<f64>]) -> DMatrix<f64> {
+    let k = errors.len();
+    let n = errors.first().map_or(0, |e| e.len()).max(1) as f64;
+    DMatrix::from_fn(k, k, |i, j| errors[i].iter().zip(errors[j].iter()).map(|(a, b)| a * b).sum::<f64>() / n)
+}
+
+// Correlation of the holdout errors; perfectly correlated errors are assumed without a holdout
+pub fn error_correlation(errors: &[Vec<f64>]) -> DMatrix<f64> {
+    let covariance = error_covariance(errors);
+    DMatrix::from_fn(covariance.nrows(), covariance.ncols(), |i, j| {
+        let scale = (covariance[(i, i)] * covariance[(j, j)]).sqrt();
+        if i == j || errors[0].is_empty() || scale == 0.0 { 1.0 } else { covariance[(i, j)] / scale }
+    })
+}
+
+// Combination weights: "mean" and "median" weigh the models equally, "inverse_mse" in
+// proportion to the inverse holdout MSE and "bates_granger" minimizes the variance of
+// the combined error, sum(w) = 1 with the full error covariance (weights may be negative)
+pub fn combination_weight(errors: &[Vec<f64>], method: &str) -> Vec<f64> {
+    let k = errors.len();
+    if k == 0 {
+        panic!("Forecast combination needs at least one model");
+    }
+    let needs_holdout = || {
+        if errors[0].is_empty() {
+            panic!("Weights of {} are estimated on a holdout", method);
+        }
+    };
+    match method {
+        "mean" | "median" => vec![1.0 / k as f64; k],
+        "inverse_mse" => {
+            needs_holdout();
+            let covariance = error_covariance(errors);
+            let inverse: Vec<f64> = (0..k).map(|i| 1.0 / covariance[(i, i)].max(f64::MIN_POSITIVE)).collect();
+            let total: f64 = inverse.iter().sum();
+            inverse.iter().map(|v| v / total).collect()
+        }
+        "bates_granger" => {
+            needs_holdout();
+            let inverse = match error_covariance(errors).try_inverse() {
+                Some(inverse) => inverse,
+                None => panic!("Holdout errors of the models are collinear, use a longer holdout or inverse_mse"),
+            };
+            let weight = &inverse * DVector::from_element(k, 1.0);
+            let total = weight.sum();
+            weight.iter().map(|w| w / total).collect()
+        }
+        _ => panic!("Unknown combination method: {}", method),
+    }
+}
@@ -0,0 +1,115 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct ForecastCombination {
+    data: Vec<f64>,
+    data_header: String,
+    holdout: usize,                  // Last observations held out to estimate the weights
+    horizon: usize,
+    member: Vec<String>,             // Names of the combined models
+    holdout_forecast: Vec<Vec<f64>>, // Forecasts of the holdout by each model refitted without it
+    member_forecast: Vec<Vec<f64>>,  // Forecasts of each model fitted on the whole series
+    member_se: Vec<Vec<f64>>,        // Standard errors of those forecasts
+    holdout_mse: Vec<f64>,
+    weight: Vec<f64>,
+    forecast: Vec<f64>,              // Combined forecast
+    lower_bound: Vec<f64>,           // Combined prediction interval
+    upper_bound: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl ForecastCombination {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, holdout: usize, horizon: usize) -> ForecastCombination {
+        ForecastCombination {
+            data,
+            data_header,
+            holdout,
+            horizon,
+            member: Vec::new(),
+            holdout_forecast: Vec::new(),
+            member_forecast: Vec::new(),
+            member_se: Vec::new(),
+            holdout_mse: Vec::new(),
+            weight: Vec::new(),
+            forecast: Vec::new(),
+            lower_bound: Vec::new(),
+            upper_bound: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String {
+        self.data_header.clone()
+    }
+    pub fn get_holdout(&self) -> usize {
+        self.holdout
+    }
+    pub fn get_horizon(&self) -> usize {
+        self.horizon
+    }
+    pub fn get_member(&self) -> Vec<String> {
+        self.member.clone()
+    }
+    pub fn get_holdout_mse(&self) -> Vec<f64> {
+        self.holdout_mse.clone()
+    }
+    pub fn get_weight(&self) -> Vec<f64> {
+        self.weight.clone()
+    }
+    pub fn get_forecast(&self) -> Vec<f64> {
+        self.forecast.clone()
+    }
+    pub fn get_lower_bound(&self) -> Vec<f64> {
+        self.lower_bound.clone()
+    }
+    pub fn get_upper_bound(&self) -> Vec<f64> {
+        self.upper_bound.clone()
+    }
+    pub fn get_member_forecast(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.member_forecast).unwrap()
+    }
+
+    // Setters
+    pub fn set_holdout_mse(&mut self, holdout_mse: Vec<f64>) {
+        self.holdout_mse = holdout_mse;
+    }
+    pub fn set_weight(&mut self, weight: Vec<f64>) {
+        self.weight = weight;
+    }
+    pub fn set_forecast(&mut self, forecast: Vec<f64>) {
+        self.forecast = forecast;
+    }
+    pub fn set_lower_bound(&mut self, lower_bound: Vec<f64>) {
+        self.lower_bound = lower_bound;
+    }
+    pub fn set_upper_bound(&mut self, upper_bound: Vec<f64>) {
+        self.upper_bound = upper_bound;
+    }
+}
+
+impl ForecastCombination {
+    pub fn get_holdout_forecast(&self) -> Vec<Vec<f64>> {
+        self.holdout_forecast.clone()
+    }
+    pub fn get_member_se(&self) -> Vec<Vec<f64>> {
+        self.member_se.clone()
+    }
+    pub fn get_member_forecast_values(&self) -> Vec<Vec<f64>> {
+        self.member_forecast.clone()
+    }
+
+    // Store the forecasts of one more model
+    pub fn push_member(&mut self, name: String, holdout_forecast: Vec<f64>, forecast: Vec<f64>, forecast_se: Vec<f64>) {
+        if holdout_forecast.len() != self.holdout || forecast.len() != self.horizon || forecast_se.len() != self.horizon {
+            panic!("Forecasts of {} must cover the holdout and the horizon", name);
+        }
+        self.member.push(name);
+        self.holdout_forecast.push(holdout_forecast);
+        self.member_forecast.push(forecast);
+        self.member_se.push(forecast_se);
+    }
+}
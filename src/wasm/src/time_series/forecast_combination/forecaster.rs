@@ -0,0 +1,267 @@
+use crate::{Arima, Benchmark, Decomposition, Smoothing, StructuralModel};
+use crate::{QuadraticRegression, SimpleExponentialRegression, SimpleLinearRegression};
+use crate::time_series::benchmark::prediction_interval::{interval_multiplier, residual_sd};
+use crate::time_series::smoothing::intermittent_demand::intermittent_demand;
+use crate::time_series::transformation::power_transform::{back_transform, inverse_transform};
+
+// Common interface of the forecasting models. A forecaster refits itself on the first n
+// observations of its series and returns the mean forecasts with their standard errors
+pub trait Forecaster {
+    fn series(&self) -> Vec<f64>;
+    fn forecast_from(&self, n: usize, horizon: usize) -> (Vec<f64>, Vec<f64>);
+
+    // Forecasts of the whole series
+    fn forecast(&self, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        self.forecast_from(self.series().len(), horizon)
+    }
+}
+
+// Standard errors on the original scale from those on the transformed scale, taken as
+// half the width of the back-transformed one standard error band
+pub fn original_scale_se(method: &str, lambda: f64, forecast: &[f64], se: &[f64]) -> Vec<f64> {
+    forecast
+        .iter()
+        .zip(se.iter())
+        .map(|(y, s)| (inverse_transform(method, y + s, lambda, 0.0) - inverse_transform(method, y - s, lambda, 0.0)) / 2.0)
+        .collect()
+}
+
+fn check_sample(series: &[f64], n: usize) {
+    if n < 2 || n > series.len() {
+        panic!("Forecast origin must be between 2 and the series length");
+    }
+}
+
+impl Forecaster for Arima {
+    fn series(&self) -> Vec<f64> {
+        self.get_original_data()
+    }
+
+    fn forecast_from(&self, n: usize, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        let series = self.series();
+        check_sample(&series, n);
+        let mut model = Arima::new(series[..n].to_vec(), self.get_ar_order(), self.get_i_order(), self.get_ma_order());
+        let method = self.get_transformation();
+        if method != "none" {
+            model.transform_data(method.clone(), "manual".to_string(), self.get_lambda(), 1);
+        }
+        model.estimate_coef();
+        let variance = model.forecast_variance(horizon);
+        let forecast = model.model_forecast(horizon);
+        let se: Vec<f64> = variance.iter().map(|v| v.sqrt()).collect();
+        (
+            back_transform(&method, self.get_lambda(), &forecast, &variance),
+            original_scale_se(&method, self.get_lambda(), &forecast, &se),
+        )
+    }
+}
+
+// Smoothing method with its parameters: "ses" and "des" [alpha], "holt" [alpha, beta],
+// "winter" [alpha, beta, gamma] with the period, and "croston", "sba" or "tsb" [alpha, beta]
+pub struct SmoothingForecaster<'a> {
+    pub smoothing: &'a Smoothing,
+    pub method: String,
+    pub parameter: Vec<f64>,
+    pub period: usize,
+}
+
+impl Forecaster for SmoothingForecaster<'_> {
+    fn series(&self) -> Vec<f64> {
+        self.smoothing.get_data()
+    }
+
+    fn forecast_from(&self, n: usize, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        let series = self.series();
+        check_sample(&series, n);
+        let parameter = |i: usize| match self.parameter.get(i) {
+            Some(value) => *value,
+            None => panic!("Smoothing method {} needs {} parameters", self.method, i + 1),
+        };
+
+        // In-sample one-step forecasts, and the h-step forecasts from the final states: a flat
+        // level for "ses" and the intermittent rates, a_n + h b_n for Brown's "des", l_n + h b_n
+        // for "holt", and (l_n + h b_n) s_(n - m + h) for "winter"
+        let mut time = self.smoothing.get_time();
+        time.truncate(n);
+        let model = Smoothing::new(self.smoothing.get_data_header(), series[..n].to_vec(), self.smoothing.get_time_header(), time);
+        let smoothed = |values: Vec<f64>, alpha: f64| {
+            let mut extended = values;
+            extended.push(f64::NAN);
+            Smoothing::new(String::new(), extended, String::new(), Vec::new()).calculate_ses(alpha)
+        };
+        let steps = 1..=horizon;
+        let (values, start, forecast): (Vec<f64>, usize, Vec<f64>) = match self.method.as_str() {
+            "ses" => {
+                let level = smoothed(series[..n].to_vec(), parameter(0))[n];
+                (model.calculate_ses(parameter(0)), 1, vec![level; horizon])
+            }
+            "des" => {
+                let alpha = parameter(0);
+                let first = smoothed(series[..n].to_vec(), alpha);
+                let second = smoothed(first[1..=n].to_vec(), alpha);
+                let (s1, s2) = (first[n], second[n]);
+                let (a, b) = (2.0 * s1 - s2, alpha / (1.0 - alpha) * (s1 - s2));
+                (model.calculate_des(alpha), 2, steps.map(|h| a + h as f64 * b).collect())
+            }
+            "holt" => {
+                let (level, trend, fitted) = model.holt_components(parameter(0), parameter(1));
+                (fitted, 2, steps.map(|h| level[n - 1] + h as f64 * trend[n - 1]).collect())
+            }
+            "winter" => {
+                let m = self.period;
+                let (level, trend, seasonal, fitted) = model.winter_components(parameter(0), parameter(1), parameter(2), m);
+                let forecast = steps.map(|h| (level[n - 1] + h as f64 * trend[n - 1]) * seasonal[n - m + (h - 1) % m]).collect();
+                (fitted, m, forecast)
+            }
+            "croston" | "sba" | "tsb" => {
+                let (fitted, rate) = intermittent_demand(&series[..n], &self.method, parameter(0), parameter(1));
+                (fitted, 1, vec![rate; horizon])
+            }
+            _ => panic!("Unknown smoothing method: {}", self.method),
+        };
+        let fitted: Vec<f64> = (0..n).map(|t| if t < start { f64::NAN } else { values[t] }).collect();
+        let sd = residual_sd(&series[..n], &fitted, 0);
+
+        // Error variance of simple exponential smoothing, random walk growth otherwise
+        let forecast_se: Vec<f64> = (1..=horizon)
+            .map(|h| match self.method.as_str() {
+                "ses" | "croston" | "sba" | "tsb" => sd * (1.0 + (h as f64 - 1.0) * parameter(0).powi(2)).sqrt(),
+                _ => sd * (h as f64).sqrt(),
+            })
+            .collect();
+        (forecast, forecast_se)
+    }
+}
+
+// Decomposition with its method, "additive" or "multiplicative" with a "linear", "quadratic"
+// or "exponential" trend. The trend is extrapolated and the seasonal indices repeated
+pub struct DecompositionForecaster<'a> {
+    pub decomposition: &'a Decomposition,
+    pub method: String,
+    pub trend: String,
+}
+
+// Trend curve fitted to the trend component, evaluated at the time points 1..=n + horizon
+fn trend_curve(component: &[f64], trend: &str, horizon: usize) -> Vec<f64> {
+    let t: Vec<f64> = (1..=component.len()).map(|i| i as f64).collect();
+    let future = 1..=component.len() + horizon;
+    match trend {
+        "linear" => {
+            let mut regression = SimpleLinearRegression::new(t, component.to_vec());
+            regression.calculate_regression();
+            future.map(|i| regression.get_b0() + regression.get_b1() * i as f64).collect()
+        }
+        "quadratic" => {
+            let mut regression = QuadraticRegression::new(t, component.to_vec());
+            regression.calculate_regression();
+            let beta = regression.get_beta();
+            future.map(|i| beta[0] + beta[1] * i as f64 + beta[2] * (i as f64).powi(2)).collect()
+        }
+        "exponential" => {
+            let mut regression = SimpleExponentialRegression::new(t, component.to_vec());
+            regression.calculate_regression();
+            future.map(|i| regression.get_b0() * (regression.get_b1() * i as f64).exp()).collect()
+        }
+        _ => panic!("Unknown trend: {}", trend),
+    }
+}
+
+impl Forecaster for DecompositionForecaster<'_> {
+    fn series(&self) -> Vec<f64> {
+        self.decomposition.get_original_data()
+    }
+
+    fn forecast_from(&self, n: usize, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        let series = self.series();
+        check_sample(&series, n);
+        let source = self.decomposition;
+        let mut time = source.get_time();
+        time.truncate(n);
+        let mut model = Decomposition::new(series[..n].to_vec(), source.get_data_header(), time, source.get_time_header(), source.get_period());
+        let transformation = source.get_transformation();
+        if transformation != "none" {
+            model.transform_data(transformation.clone(), "manual".to_string(), source.get_lambda());
+        }
+
+        // Components on the scale the decomposition works on; the additive trend is a moving
+        // average and is extrapolated by a straight line
+        let multiplicative = match self.method.as_str() {
+            "additive" => {
+                model.additive_decomposition();
+                false
+            }
+            "multiplicative" => {
+                model.multiplicative_decomposition(self.trend.clone());
+                true
+            }
+            _ => panic!("Unknown decomposition method: {}", self.method),
+        };
+        let curve = trend_curve(&model.get_trend_component(), if multiplicative { &self.trend } else { "linear" }, horizon);
+        let indices = model.get_seasonal_indices();
+        let combine = |t: usize, trend: f64| {
+            let season = indices[t % indices.len()];
+            if multiplicative { trend * season } else { trend + season }
+        };
+        let trend_component = model.get_trend_component();
+        let fitted: Vec<f64> = (0..n).map(|t| combine(t, trend_component[t])).collect();
+        let forecast: Vec<f64> = (n..n + horizon).map(|t| combine(t, curve[t])).collect();
+
+        // The irregular variation gives the error of every forecast
+        let sd = residual_sd(&model.get_data(), &fitted, 0);
+        let variance = vec![sd * sd; horizon];
+        (
+            back_transform(&transformation, source.get_lambda(), &forecast, &variance),
+            original_scale_se(&transformation, source.get_lambda(), &forecast, &vec![sd; horizon]),
+        )
+    }
+}
+
+// Benchmark method: "naive", "seasonal_naive", "drift", "mean" or "theta"
+pub struct BenchmarkForecaster<'a> {
+    pub benchmark: &'a Benchmark,
+    pub method: String,
+}
+
+impl Forecaster for BenchmarkForecaster<'_> {
+    fn series(&self) -> Vec<f64> {
+        self.benchmark.get_data()
+    }
+
+    fn forecast_from(&self, n: usize, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        let series = self.series();
+        check_sample(&series, n);
+        let source = self.benchmark;
+        let mut time = source.get_time();
+        time.truncate(n);
+        let mut model = Benchmark::new(series[..n].to_vec(), source.get_data_header(), time, source.get_time_header(), source.get_period());
+        let level = 0.95;
+        let forecast = match self.method.as_str() {
+            "naive" => model.calculate_naive(horizon, level),
+            "seasonal_naive" => model.calculate_seasonal_naive(horizon, level),
+            "drift" => model.calculate_drift(horizon, level),
+            "mean" => model.calculate_mean(horizon, level),
+            "theta" => model.calculate_theta(horizon, level, "auto".to_string()),
+            _ => panic!("Unknown benchmark method: {}", self.method),
+        };
+        let z = interval_multiplier(level);
+        let forecast_se = model.get_upper_bound().iter().zip(forecast.iter()).map(|(u, f)| (u - f) / z).collect();
+        (forecast, forecast_se)
+    }
+}
+
+impl Forecaster for StructuralModel {
+    fn series(&self) -> Vec<f64> {
+        self.get_data()
+    }
+
+    // Refits the specification of the last calculate_structural_model
+    fn forecast_from(&self, n: usize, horizon: usize) -> (Vec<f64>, Vec<f64>) {
+        let series = self.series();
+        check_sample(&series, n);
+        let mut time = self.get_time();
+        time.truncate(n);
+        let mut model = StructuralModel::new(series[..n].to_vec(), self.get_data_header(), time, self.get_time_header());
+        let forecast = model.calculate_structural_model(self.get_trend(), self.get_seasonal(), self.get_period(), self.get_cycle(), horizon, 0.95);
+        (forecast, model.get_forecast_se())
+    }
+}
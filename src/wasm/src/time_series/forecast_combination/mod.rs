@@ -0,0 +1,7 @@
+pub mod forecaster;
+pub mod forecast_combination;
+pub mod add_member;
+pub mod combination_weight;
+pub mod calculate_combination;
+
+pub use forecast_combination::ForecastCombination;
\ No newline at end of file
@@ -0,0 +1,85 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Array, Object, Reflect};
+use statrs::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor};
+use crate::{GrangerCausality, MultipleLinearRegression};
+use crate::time_series::missing_value::complete_cases::complete_cases;
+
+// Residual sums of squares of the regressions of y_t on a constant and lags 1..=lag of y
+// (restricted) and also of x (unrestricted), over the same complete rows. Returns
+// (rss restricted, rss unrestricted, rows)
+pub fn lag_regression_rss(cause: &[f64], effect: &[f64], lag: usize) -> (f64, f64, usize) {
+    let n = effect.len();
+    let mut columns: Vec<Vec<f64>> = Vec::new();
+    for j in 1..=lag {
+        columns.push((lag..n).map(|t| effect[t - j]).collect());
+    }
+    for j in 1..=lag {
+        columns.push((lag..n).map(|t| cause[t - j]).collect());
+    }
+    columns.push(effect[lag..].to_vec());
+    let rows = complete_cases(columns);
+    let y = rows[2 * lag].clone();
+    if y.len() <= 2 * lag + 1 {
+        panic!("Granger test at lag {} needs more than {} complete observations", lag, 2 * lag + 1);
+    }
+    let rss = |x: Vec<Vec<f64>>| {
+        let mut regression = MultipleLinearRegression::from_columns(x, y.clone());
+        regression.calculate_regression();
+        regression.calculate_sse()
+    };
+    (rss(rows[..lag].to_vec()), rss(rows[..2 * lag].to_vec()), y.len())
+}
+
+// F and chi-square statistics of the lagged cause: [F, df1, df2, p] and [chi-square, p]
+pub fn granger_statistic(cause: &[f64], effect: &[f64], lag: usize) -> ([f64; 4], [f64; 2]) {
+    let (rss_restricted, rss_unrestricted, n) = lag_regression_rss(cause, effect, lag);
+    let df1 = lag as f64;
+    let df2 = (n - 2 * lag - 1) as f64;
+    let f = ((rss_restricted - rss_unrestricted) / df1) / (rss_unrestricted / df2);
+    let f_p_value = 1.0 - FisherSnedecor::new(df1, df2).unwrap().cdf(f.max(0.0));
+    let chi = n as f64 * (rss_restricted - rss_unrestricted) / rss_unrestricted;
+    let chi_p_value = 1.0 - ChiSquared::new(df1).unwrap().cdf(chi.max(0.0));
+    ([f, df1, df2, f_p_value], [chi, chi_p_value])
+}
+
+#[wasm_bindgen]
+impl GrangerCausality {
+    // Granger causality in both directions for every lag from 1 to max_lag
+    pub fn calculate_granger_causality(&mut self, max_lag: usize) -> JsValue {
+        if max_lag < 1 {
+            panic!("Granger test needs a lag of at least 1");
+        }
+        let (x, y) = (self.get_data_x(), self.get_data_y());
+        let forward = format!("{} -> {}", self.get_header_x(), self.get_header_y());
+        let backward = format!("{} -> {}", self.get_header_y(), self.get_header_x());
+        self.clear_result();
+        for lag in 1..=max_lag {
+            let (f, chi) = granger_statistic(&x, &y, lag);
+            self.push_result(forward.clone(), lag, f, chi);
+            let (f, chi) = granger_statistic(&y, &x, lag);
+            self.push_result(backward.clone(), lag, f, chi);
+        }
+        self.check_stationarity();
+        self.granger_table()
+    }
+
+    // One row per direction and lag
+    pub fn granger_table(&self) -> JsValue {
+        let rows = Array::new();
+        let (f, df1, df2, f_p) = (self.get_f_stat(), self.get_df_numerator(), self.get_df_denominator(), self.get_f_p_value());
+        let (chi, chi_p) = (self.get_chi_square(), self.get_chi_p_value());
+        for (i, direction) in self.get_direction().iter().enumerate() {
+            let row = Object::new();
+            Reflect::set(&row, &"Direction".into(), &direction.into()).unwrap();
+            Reflect::set(&row, &"Lag".into(), &(self.get_lag()[i] as f64).into()).unwrap();
+            Reflect::set(&row, &"F".into(), &f[i].into()).unwrap();
+            Reflect::set(&row, &"df1".into(), &df1[i].into()).unwrap();
+            Reflect::set(&row, &"df2".into(), &df2[i].into()).unwrap();
+            Reflect::set(&row, &"Sig. F".into(), &f_p[i].into()).unwrap();
+            Reflect::set(&row, &"Chi-Square".into(), &chi[i].into()).unwrap();
+            Reflect::set(&row, &"Sig. Chi-Square".into(), &chi_p[i].into()).unwrap();
+            rows.push(&row);
+        }
+        JsValue::from(rows)
+    }
+}
@@ -0,0 +1,116 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct GrangerCausality {
+    data_x: Vec<f64>,
+    header_x: String,
+    data_y: Vec<f64>,
+    header_y: String,
+    direction: Vec<String>, // "x -> y" rows test whether x Granger-causes y
+    lag: Vec<usize>,
+    f_stat: Vec<f64>,
+    df_numerator: Vec<f64>,
+    df_denominator: Vec<f64>,
+    f_p_value: Vec<f64>,
+    chi_square: Vec<f64>,
+    chi_p_value: Vec<f64>,
+    warning: Vec<String>,   // Stationarity warnings from the ADF test
+}
+
+#[wasm_bindgen]
+impl GrangerCausality {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data_x: Vec<f64>, header_x: String, data_y: Vec<f64>, header_y: String) -> GrangerCausality {
+        if data_x.len() != data_y.len() {
+            panic!("Both series must have the same length");
+        }
+        GrangerCausality {
+            data_x,
+            header_x,
+            data_y,
+            header_y,
+            direction: Vec::new(),
+            lag: Vec::new(),
+            f_stat: Vec::new(),
+            df_numerator: Vec::new(),
+            df_denominator: Vec::new(),
+            f_p_value: Vec::new(),
+            chi_square: Vec::new(),
+            chi_p_value: Vec::new(),
+            warning: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data_x(&self) -> Vec<f64> {
+        self.data_x.clone()
+    }
+    pub fn get_header_x(&self) -> String {
+        self.header_x.clone()
+    }
+    pub fn get_data_y(&self) -> Vec<f64> {
+        self.data_y.clone()
+    }
+    pub fn get_header_y(&self) -> String {
+        self.header_y.clone()
+    }
+    pub fn get_direction(&self) -> Vec<String> {
+        self.direction.clone()
+    }
+    pub fn get_lag(&self) -> Vec<usize> {
+        self.lag.clone()
+    }
+    pub fn get_f_stat(&self) -> Vec<f64> {
+        self.f_stat.clone()
+    }
+    pub fn get_df_numerator(&self) -> Vec<f64> {
+        self.df_numerator.clone()
+    }
+    pub fn get_df_denominator(&self) -> Vec<f64> {
+        self.df_denominator.clone()
+    }
+    pub fn get_f_p_value(&self) -> Vec<f64> {
+        self.f_p_value.clone()
+    }
+    pub fn get_chi_square(&self) -> Vec<f64> {
+        self.chi_square.clone()
+    }
+    pub fn get_chi_p_value(&self) -> Vec<f64> {
+        self.chi_p_value.clone()
+    }
+    pub fn get_warning(&self) -> Vec<String> {
+        self.warning.clone()
+    }
+
+    // Setters
+    pub fn set_warning(&mut self, warning: Vec<String>) {
+        self.warning = warning;
+    }
+}
+
+impl GrangerCausality {
+    // Clear the results before a new calculation
+    pub fn clear_result(&mut self) {
+        self.direction.clear();
+        self.lag.clear();
+        self.f_stat.clear();
+        self.df_numerator.clear();
+        self.df_denominator.clear();
+        self.f_p_value.clear();
+        self.chi_square.clear();
+        self.chi_p_value.clear();
+    }
+
+    // Append one row of the results: direction, lag, F, its degrees of freedom, chi-square
+    // and the p-values of both statistics
+    pub fn push_result(&mut self, direction: String, lag: usize, f: [f64; 4], chi: [f64; 2]) {
+        self.direction.push(direction);
+        self.lag.push(lag);
+        self.f_stat.push(f[0]);
+        self.df_numerator.push(f[1]);
+        self.df_denominator.push(f[2]);
+        self.f_p_value.push(f[3]);
+        self.chi_square.push(chi[0]);
+        self.chi_p_value.push(chi[1]);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod granger_causality;
+pub mod calculate_granger;
+pub mod stationarity_check;
+
+pub use granger_causality::GrangerCausality;
\ No newline at end of file
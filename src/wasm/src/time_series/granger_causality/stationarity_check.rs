@@ -0,0 +1,21 @@
+use wasm_bindgen::prelude::*;
+use crate::{AugmentedDickeyFuller, GrangerCausality};
+
+#[wasm_bindgen]
+impl GrangerCausality {
+    // The test assumes stationary series: warn when the ADF test (constant, one lag)
+    // does not reject a unit root at 5%
+    pub fn check_stationarity(&mut self) -> Vec<String> {
+        let mut warning = Vec::new();
+        for (data, header) in [(self.get_data_x(), self.get_header_x()), (self.get_data_y(), self.get_header_y())] {
+            let mut adf = AugmentedDickeyFuller::new(data, "no_trend".to_string(), "level".to_string(), 1);
+            adf.calculate_test_stat();
+            let p_value = adf.calculate_pvalue();
+            if p_value >= 0.05 || p_value.is_nan() {
+                warning.push(format!("{} may be non-stationary (ADF p-value {:.3}); consider differencing it first", header, p_value));
+            }
+        }
+        self.set_warning(warning.clone());
+        warning
+    }
+}
@@ -0,0 +1,6 @@
+// Keep only the rows where every column is observed (listwise deletion of NaN)
+pub fn complete_cases(columns: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = columns.iter().map(|c| c.len()).min().unwrap_or(0);
+    let keep: Vec<usize> = (0..n).filter(|i| columns.iter().all(|c| !c[*i].is_nan())).collect();
+    columns.iter().map(|c| keep.iter().map(|i| c[*i]).collect()).collect()
+}
\ No newline at end of file
@@ -0,0 +1,123 @@
+use wasm_bindgen::prelude::*;
+use crate::MissingValue;
+
+// Positions and values of the observed (non-NaN) points
+pub fn observed_points(data: &[f64]) -> (Vec<usize>, Vec<f64>) {
+    let index: Vec<usize> = (0..data.len()).filter(|i| !data[*i].is_nan()).collect();
+    let values: Vec<f64> = index.iter().map(|i| data[*i]).collect();
+    (index, values)
+}
+
+// Linear interpolation between observed points, constant beyond the first and last ones
+pub fn linear_interpolation(data: &[f64]) -> Vec<f64> {
+    let (index, values) = observed_points(data);
+    if index.is_empty() {
+        return data.to_vec();
+    }
+    let mut filled: Vec<f64> = data.to_vec();
+    let mut next = 0;
+    for (i, value) in filled.iter_mut().enumerate() {
+        while next < index.len() && index[next] < i {
+            next += 1;
+        }
+        if !value.is_nan() {
+            continue;
+        }
+        *value = if next == 0 {
+            values[0]
+        } else if next == index.len() {
+            values[index.len() - 1]
+        } else {
+            let (x0, x1) = (index[next - 1] as f64, index[next] as f64);
+            values[next - 1] + (values[next] - values[next - 1]) * (i as f64 - x0) / (x1 - x0)
+        };
+    }
+    filled
+}
+
+// Natural cubic spline through the observed points, constant beyond the first and last ones
+pub fn spline_interpolation(data: &[f64]) -> Vec<f64> {
+    let (index, values) = observed_points(data);
+    let m = index.len();
+    if m < 3 {
+        return linear_interpolation(data);
+    }
+
+    // Solve the tridiagonal system for the second derivatives
+    let x: Vec<f64> = index.iter().map(|i| *i as f64).collect();
+    let h: Vec<f64> = (0..m - 1).map(|i| x[i + 1] - x[i]).collect();
+    let mut second: Vec<f64> = vec![0.0; m];
+    let mut diag: Vec<f64> = vec![0.0; m];
+    let mut rhs: Vec<f64> = vec![0.0; m];
+    for i in 1..m - 1 {
+        diag[i] = 2.0 * (h[i - 1] + h[i]);
+        rhs[i] = 6.0 * ((values[i + 1] - values[i]) / h[i] - (values[i] - values[i - 1]) / h[i - 1]);
+    }
+    for i in 2..m - 1 {
+        let w = h[i - 1] / diag[i - 1];
+        diag[i] -= w * h[i - 1];
+        rhs[i] -= w * rhs[i - 1];
+    }
+    for i in (1..m - 1).rev() {
+        second[i] = (rhs[i] - h[i] * second[i + 1]) / diag[i];
+    }
+
+    let mut filled: Vec<f64> = data.to_vec();
+    let mut segment = 0;
+    for (i, value) in filled.iter_mut().enumerate() {
+        if !value.is_nan() {
+            continue;
+        }
+        let xi = i as f64;
+        if xi < x[0] {
+            *value = values[0];
+            continue;
+        }
+        if xi > x[m - 1] {
+            *value = values[m - 1];
+            continue;
+        }
+        while x[segment + 1] < xi {
+            segment += 1;
+        }
+        let (a, b) = (x[segment + 1] - xi, xi - x[segment]);
+        let hs = h[segment];
+        *value = second[segment] * a.powi(3) / (6.0 * hs) + second[segment + 1] * b.powi(3) / (6.0 * hs)
+            + (values[segment] / hs - second[segment] * hs / 6.0) * a
+            + (values[segment + 1] / hs - second[segment + 1] * hs / 6.0) * b;
+    }
+    filled
+}
+
+// Last observation carried forward, leading gaps take the first observation
+pub fn locf(data: &[f64]) -> Vec<f64> {
+    let first = data.iter().find(|x| !x.is_nan()).cloned().unwrap_or(f64::NAN);
+    let mut last = first;
+    data.iter().map(|x| {
+        if !x.is_nan() {
+            last = *x;
+        }
+        last
+    }).collect()
+}
+
+#[wasm_bindgen]
+impl MissingValue{
+    pub fn calculate_linear_interpolation(&mut self) -> Vec<f64>{
+        let imputed: Vec<f64> = linear_interpolation(&self.get_data());
+        self.set_imputed_data(imputed.clone());
+        imputed
+    }
+
+    pub fn calculate_spline_interpolation(&mut self) -> Vec<f64>{
+        let imputed: Vec<f64> = spline_interpolation(&self.get_data());
+        self.set_imputed_data(imputed.clone());
+        imputed
+    }
+
+    pub fn calculate_locf(&mut self) -> Vec<f64>{
+        let imputed: Vec<f64> = locf(&self.get_data());
+        self.set_imputed_data(imputed.clone());
+        imputed
+    }
+}
@@ -0,0 +1,85 @@
+use wasm_bindgen::prelude::*;
+use crate::{Arima, MissingValue};
+use crate::time_series::missing_value::interpolation::linear_interpolation;
+use crate::time_series::state_space::StateSpaceModel;
+
+// Concentrated log-likelihood of the local level model for a signal-to-noise ratio q
+fn local_level_log_likelihood(data: &[f64], q: f64) -> f64 {
+    let filter = StateSpaceModel::local_level(1.0, q).kalman_filter(data);
+    let (sum_square, count) = filter.sum_square_innovation();
+    if count == 0 || sum_square <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    -0.5 * (count as f64 * (sum_square / count as f64).ln() + filter.sum_log_variance())
+}
+
+// Maximum likelihood noise and level variances of the local level model
+pub fn estimate_local_level(data: &[f64]) -> (f64, f64) {
+    // Grid search on log q followed by golden section refinement
+    let mut best: f64 = -10.0;
+    let mut best_ll = f64::NEG_INFINITY;
+    let mut log_q: f64 = -10.0;
+    while log_q <= 5.0 {
+        let ll = local_level_log_likelihood(data, log_q.exp());
+        if ll > best_ll {
+            best_ll = ll;
+            best = log_q;
+        }
+        log_q += 0.5;
+    }
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut lower, mut upper) = (best - 0.5, best + 0.5);
+    for _ in 0..40 {
+        let x1 = upper - ratio * (upper - lower);
+        let x2 = lower + ratio * (upper - lower);
+        if local_level_log_likelihood(data, x1.exp()) > local_level_log_likelihood(data, x2.exp()) {
+            upper = x2;
+        } else {
+            lower = x1;
+        }
+    }
+    let q = ((lower + upper) / 2.0).exp();
+    let filter = StateSpaceModel::local_level(1.0, q).kalman_filter(data);
+    let (sum_square, count) = filter.sum_square_innovation();
+    let h = if count > 0 { sum_square / count as f64 } else { 1.0 };
+    (h, q * h)
+}
+
+// Smoothed values of the missing points under a local level model
+pub fn local_level_imputation(data: &[f64]) -> Vec<f64> {
+    let (h, q) = estimate_local_level(data);
+    let model = StateSpaceModel::local_level(h, q);
+    let smoothed: Vec<f64> = model.smoothed_signal(&model.kalman_smoother(&model.kalman_filter(data)));
+    data.iter().zip(smoothed.iter()).map(|(x, s)| if x.is_nan() { *s } else { *x }).collect()
+}
+
+// Smoothed values of the missing points under an ARIMA model; the coefficients are
+// estimated on the interpolated series and the imputation is repeated twice
+pub fn arima_imputation(data: &[f64], ar_order: i32, i_order: i32, ma_order: i32) -> Vec<f64> {
+    let mut filled: Vec<f64> = linear_interpolation(data);
+    for _ in 0..2 {
+        let mut arima = Arima::new(filled.clone(), ar_order, i_order, ma_order);
+        arima.estimate_coef();
+        let ar_coef: Vec<f64> = arima.get_ar_coef();
+        let mean = if i_order == 0 { arima.get_constant() / (1.0 - ar_coef.iter().sum::<f64>()) } else { 0.0 };
+        let model = StateSpaceModel::arima(&ar_coef, &arima.get_ma_coef(), i_order as usize, mean);
+        let smoothed: Vec<f64> = model.smoothed_signal(&model.kalman_smoother(&model.kalman_filter(data)));
+        filled = data.iter().zip(smoothed.iter()).map(|(x, s)| if x.is_nan() { *s } else { *x }).collect();
+    }
+    filled
+}
+
+#[wasm_bindgen]
+impl MissingValue{
+    // Kalman smoothing on a local level ("local_level") or ARIMA ("arima") state space model
+    pub fn calculate_kalman_smoothing(&mut self, model: String, ar_order: i32, i_order: i32, ma_order: i32) -> Vec<f64>{
+        let data: Vec<f64> = self.get_data();
+        let imputed: Vec<f64> = match model.as_str() {
+            "local_level" => local_level_imputation(&data),
+            "arima" => arima_imputation(&data, ar_order, i_order, ma_order),
+            _ => panic!("Unknown model: {}", model),
+        };
+        self.set_imputed_data(imputed.clone());
+        imputed
+    }
+}
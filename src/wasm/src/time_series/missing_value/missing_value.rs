@@ -0,0 +1,63 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct MissingValue {
+    data: Vec<f64>, // Missing values are NaN
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    imputed_data: Vec<f64>,
+    imputed_index: Vec<usize>,
+}
+
+#[wasm_bindgen]
+impl MissingValue{
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String) -> MissingValue{
+        let imputed_index: Vec<usize> = (0..data.len()).filter(|i| data[*i].is_nan()).collect();
+        MissingValue{
+            data,
+            data_header,
+            time,
+            time_header,
+            imputed_data: Vec::new(),
+            imputed_index,
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64>{
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String{
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String>{
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String{
+        self.time_header.clone()
+    }
+    pub fn get_imputed_data(&self) -> Vec<f64>{
+        self.imputed_data.clone()
+    }
+    pub fn get_imputed_index(&self) -> Vec<usize>{
+        self.imputed_index.clone()
+    }
+    pub fn get_imputed_time(&self) -> Vec<String>{
+        let time: Vec<String> = self.get_time();
+        self.imputed_index.iter().map(|i| if *i < time.len() { time[*i].clone() } else { (i + 1).to_string() }).collect()
+    }
+    pub fn get_missing_count(&self) -> usize{
+        self.imputed_index.len()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>){
+        self.imputed_index = (0..data.len()).filter(|i| data[*i].is_nan()).collect();
+        self.data = data;
+    }
+    pub fn set_imputed_data(&mut self, imputed_data: Vec<f64>){
+        self.imputed_data = imputed_data;
+    }
+}
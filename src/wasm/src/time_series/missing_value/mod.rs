@@ -0,0 +1,7 @@
+pub mod missing_value;
+pub mod interpolation;
+pub mod seasonal_interpolation;
+pub mod kalman_imputation;
+pub mod complete_cases;
+
+pub use missing_value::MissingValue;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use wasm_bindgen::prelude::*;
+use crate::{MissingValue, SimpleLinearRegression};
+use crate::time_series::missing_value::interpolation::{linear_interpolation, observed_points};
+
+#[wasm_bindgen]
+impl MissingValue{
+    // Seasonal interpolation: remove additive seasonal indices, interpolate linearly and add them back
+    pub fn calculate_seasonal_interpolation(&mut self, period: i32) -> Vec<f64>{
+        let data: Vec<f64> = self.get_data();
+        let period = period.max(1) as usize;
+        let (index, values) = observed_points(&data);
+        if index.len() < 2 || period < 2 {
+            return self.calculate_linear_interpolation();
+        }
+
+        // Linear trend of the observed points
+        let t: Vec<f64> = index.iter().map(|i| *i as f64 + 1.0).collect();
+        let mut regression = SimpleLinearRegression::new(t, values.clone());
+        regression.calculate_regression();
+        let b0 = regression.get_b0();
+        let b1 = regression.get_b1();
+
+        // Seasonal indices from the detrended observations, centred to sum to zero
+        let mut sum: Vec<f64> = vec![0.0; period];
+        let mut count: Vec<f64> = vec![0.0; period];
+        for (i, value) in index.iter().zip(values.iter()){
+            sum[i % period] += value - (b0 + b1 * (*i as f64 + 1.0));
+            count[i % period] += 1.0;
+        }
+        let mut seasonal_indices: Vec<f64> = (0..period).map(|j| if count[j] > 0.0 { sum[j] / count[j] } else { 0.0 }).collect();
+        let mean_index = seasonal_indices.iter().sum::<f64>() / period as f64;
+        for s in seasonal_indices.iter_mut(){
+            *s -= mean_index;
+        }
+
+        let deseasonalized: Vec<f64> = data.iter().enumerate().map(|(i, x)| x - seasonal_indices[i % period]).collect();
+        let interpolated: Vec<f64> = linear_interpolation(&deseasonalized);
+        let imputed: Vec<f64> = data.iter().enumerate().map(|(i, x)| {
+            if x.is_nan() { interpolated[i] + seasonal_indices[i % period] } else { *x }
+        }).collect();
+        self.set_imputed_data(imputed.clone());
+        imputed
+    }
+}
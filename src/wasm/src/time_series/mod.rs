@@ -4,4 +4,18 @@ pub mod decomposition;
 pub mod autocorrelation;
 pub mod difference;
 pub mod unit_root_test;
-pub mod arima;
\ No newline at end of file
+pub mod arima;
+pub mod seasonality;
+pub mod cycle_filter;
+pub mod outlier_detection;
+pub mod change_point;
+pub mod state_space;
+pub mod missing_value;
+pub mod time_index;
+pub mod resample;
+pub mod transformation;
+pub mod benchmark;
+pub mod forecast_combination;
+pub mod reconciliation;
+pub mod structural_model;
+pub mod granger_causality;
\ No newline at end of file
@@ -0,0 +1,143 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::{DMatrix, DVector};
+use crate::{Arima, OutlierDetection};
+use crate::time_series::outlier_detection::outlier_effect::{outlier_effect, outlier_pattern, pi_weights};
+
+const OUTLIER_TYPES: [&str; 4] = ["AO", "IO", "LS", "TC"];
+
+// Robust residual standard deviation, 1.483 times the median absolute deviation of the
+// finite residuals, NaN when there are none
+fn robust_sigma(residual: &[f64]) -> f64 {
+    let mut sorted: Vec<f64> = residual.iter().filter(|e| e.is_finite()).cloned().collect();
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let median = sorted[sorted.len() / 2];
+    let mut deviation: Vec<f64> = sorted.iter().map(|e| (e - median).abs()).collect();
+    deviation.sort_by(|a, b| a.total_cmp(b));
+    1.483 * deviation[deviation.len() / 2]
+}
+
+impl OutlierDetection{
+    // ARIMA residuals aligned with the original series, pi weights and the first usable index
+    fn fit_residuals(&self, data: Vec<f64>) -> (Vec<f64>, Vec<f64>, usize) {
+        let n = data.len();
+        let d = self.get_i_order() as usize;
+        let mut arima = Arima::new(data, self.get_ar_order(), self.get_i_order(), self.get_ma_order());
+        arima.estimate_coef();
+        let residual: Vec<f64> = arima.estimate_residual();
+        let mut aligned: Vec<f64> = vec![0.0; d];
+        aligned.extend(residual);
+        let pi: Vec<f64> = pi_weights(&arima.get_ar_coef(), &arima.get_ma_coef(), d, n);
+        (aligned, pi, d + self.get_ar_order() as usize)
+    }
+}
+
+#[wasm_bindgen]
+impl OutlierDetection{
+    // Critical value of the t-statistic depending on the series length, as in tsoutliers
+    pub fn default_critical_value(&self) -> f64{
+        let n = self.get_data().len() as f64;
+        if n <= 50.0 {
+            3.0
+        } else if n >= 450.0 {
+            4.0
+        } else {
+            3.0 + (n - 50.0) / 400.0
+        }
+    }
+
+    // Chen-Liu outlier detection on the residuals of the ARIMA model
+    pub fn detect_outliers(&mut self, critical_value: f64) -> Vec<usize>{
+        let data: Vec<f64> = self.get_data();
+        let n = data.len();
+        let critical_value = if critical_value > 0.0 { critical_value } else { self.default_critical_value() };
+        let (residual, pi, start) = self.fit_residuals(data.clone());
+        let effects: Vec<Vec<f64>> = OUTLIER_TYPES.iter().map(|t| outlier_effect(t, &pi, n)).collect();
+
+        // Stage 1: locate outliers one at a time, removing each effect from the residuals
+        let mut adjusted: Vec<f64> = residual.clone();
+        let mut found: Vec<(usize, usize)> = Vec::new(); // (type, index)
+        while found.len() < n / 5 {
+            let sigma = robust_sigma(&adjusted[start.min(n)..]);
+            if sigma.is_nan() || sigma <= 0.0 {
+                break;
+            }
+            let mut best: (usize, usize, f64, f64) = (0, 0, 0.0, 0.0); // (type, index, omega, tau)
+            for t0 in start..n{
+                for (k, effect) in effects.iter().enumerate(){
+                    let x = &effect[0..n - t0];
+                    let sxx: f64 = x.iter().map(|v| v * v).sum();
+                    let sxe: f64 = x.iter().zip(adjusted[t0..].iter()).map(|(v, e)| v * e).sum();
+                    let omega = sxe / sxx;
+                    let tau = omega * sxx.sqrt() / sigma;
+                    if tau.abs() > best.3.abs() {
+                        best = (k, t0, omega, tau);
+                    }
+                }
+            }
+            if best.3.abs() < critical_value || found.iter().any(|f| f.1 == best.1) {
+                break;
+            }
+            for j in 0..n - best.1{
+                adjusted[best.1 + j] -= best.2 * effects[best.0][j];
+            }
+            found.push((best.0, best.1));
+        }
+
+        // Stage 2: estimate all effects jointly and drop the insignificant ones
+        let mut omega: Vec<f64> = Vec::new();
+        let mut t_stat: Vec<f64> = Vec::new();
+        while !found.is_empty() {
+            let rows = n - start;
+            let mut x = DMatrix::zeros(rows, found.len());
+            for (c, (k, t0)) in found.iter().enumerate(){
+                for t in *t0..n{
+                    x[(t - start, c)] = effects[*k][t - t0];
+                }
+            }
+            let y = DVector::from_column_slice(&residual[start..]);
+            let svd = x.clone().svd(true, true);
+            let beta = svd.solve(&y, 1e-10).expect("Outlier regression failed");
+            let fitted = &x * &beta;
+            let sse: f64 = (0..rows).map(|i| (y[i] - fitted[i]).powi(2)).sum();
+            let sigma2 = sse / (rows as f64 - found.len() as f64).max(1.0);
+            let xtx_inv = (x.transpose() * &x).pseudo_inverse(1e-12).expect("Outlier regression failed");
+            omega = beta.iter().cloned().collect();
+            t_stat = (0..found.len()).map(|c| beta[c] / (sigma2 * xtx_inv[(c, c)]).sqrt()).collect();
+
+            let (weakest, weakest_t) = t_stat.iter().enumerate().fold((0, f64::INFINITY), |acc, (c, t)| if t.abs() < acc.1 { (c, t.abs()) } else { acc });
+            if weakest_t >= critical_value {
+                break;
+            }
+            found.remove(weakest);
+            omega.clear();
+            t_stat.clear();
+        }
+
+        // Remove the estimated outlier effects from the series
+        let mut adjusted_data: Vec<f64> = data.clone();
+        for (c, (k, t0)) in found.iter().enumerate(){
+            let pattern: Vec<f64> = outlier_pattern(OUTLIER_TYPES[*k], &pi, n - t0);
+            for j in 0..n - t0{
+                adjusted_data[t0 + j] -= omega[c] * pattern[j];
+            }
+        }
+
+        // Report the outliers in time order
+        let mut order: Vec<usize> = (0..found.len()).collect();
+        order.sort_by_key(|c| found[*c].1);
+        let time: Vec<String> = self.get_time();
+        let outlier_index: Vec<usize> = order.iter().map(|c| found[*c].1).collect();
+        self.set_critical_value(critical_value);
+        self.set_outlier_type(order.iter().map(|c| OUTLIER_TYPES[found[*c].0].to_string()).collect());
+        self.set_outlier_time(outlier_index.iter().map(|i| if *i < time.len() { time[*i].clone() } else { (i + 1).to_string() }).collect());
+        self.set_magnitude(order.iter().map(|c| omega[*c]).collect());
+        self.set_t_stat(order.iter().map(|c| t_stat[*c]).collect());
+        self.set_adjusted_data(adjusted_data);
+        self.set_pi_weight(pi);
+        self.set_outlier_index(outlier_index.clone());
+        outlier_index
+    }
+}
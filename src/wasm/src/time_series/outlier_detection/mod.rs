@@ -0,0 +1,6 @@
+pub mod outlier_detection;
+pub mod outlier_effect;
+pub mod locate_outlier;
+pub mod refit_arima;
+
+pub use outlier_detection::OutlierDetection;
@@ -0,0 +1,117 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct OutlierDetection {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    ar_order: i32,
+    i_order: i32,
+    ma_order: i32,
+    critical_value: f64,
+    outlier_type: Vec<String>, // AO, IO, LS or TC
+    outlier_index: Vec<usize>,
+    outlier_time: Vec<String>,
+    magnitude: Vec<f64>,
+    t_stat: Vec<f64>,
+    adjusted_data: Vec<f64>,
+    pi_weight: Vec<f64>,       // pi weights of the ARIMA model used during detection
+}
+
+#[wasm_bindgen]
+impl OutlierDetection{
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String, ar_order: i32, i_order: i32, ma_order: i32) -> OutlierDetection{
+        OutlierDetection{
+            data,
+            data_header,
+            time,
+            time_header,
+            ar_order,
+            i_order,
+            ma_order,
+            critical_value: 0.0,
+            outlier_type: Vec::new(),
+            outlier_index: Vec::new(),
+            outlier_time: Vec::new(),
+            magnitude: Vec::new(),
+            t_stat: Vec::new(),
+            adjusted_data: Vec::new(),
+            pi_weight: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64>{
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String{
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String>{
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String{
+        self.time_header.clone()
+    }
+    pub fn get_ar_order(&self) -> i32{
+        self.ar_order
+    }
+    pub fn get_i_order(&self) -> i32{
+        self.i_order
+    }
+    pub fn get_ma_order(&self) -> i32{
+        self.ma_order
+    }
+    pub fn get_critical_value(&self) -> f64{
+        self.critical_value
+    }
+    pub fn get_outlier_type(&self) -> Vec<String>{
+        self.outlier_type.clone()
+    }
+    pub fn get_outlier_index(&self) -> Vec<usize>{
+        self.outlier_index.clone()
+    }
+    pub fn get_outlier_time(&self) -> Vec<String>{
+        self.outlier_time.clone()
+    }
+    pub fn get_magnitude(&self) -> Vec<f64>{
+        self.magnitude.clone()
+    }
+    pub fn get_t_stat(&self) -> Vec<f64>{
+        self.t_stat.clone()
+    }
+    pub fn get_adjusted_data(&self) -> Vec<f64>{
+        self.adjusted_data.clone()
+    }
+    pub fn get_pi_weight(&self) -> Vec<f64>{
+        self.pi_weight.clone()
+    }
+
+    // Setters
+    pub fn set_critical_value(&mut self, critical_value: f64){
+        self.critical_value = critical_value;
+    }
+    pub fn set_outlier_type(&mut self, outlier_type: Vec<String>){
+        self.outlier_type = outlier_type;
+    }
+    pub fn set_outlier_index(&mut self, outlier_index: Vec<usize>){
+        self.outlier_index = outlier_index;
+    }
+    pub fn set_outlier_time(&mut self, outlier_time: Vec<String>){
+        self.outlier_time = outlier_time;
+    }
+    pub fn set_magnitude(&mut self, magnitude: Vec<f64>){
+        self.magnitude = magnitude;
+    }
+    pub fn set_t_stat(&mut self, t_stat: Vec<f64>){
+        self.t_stat = t_stat;
+    }
+    pub fn set_adjusted_data(&mut self, adjusted_data: Vec<f64>){
+        self.adjusted_data = adjusted_data;
+    }
+    pub fn set_pi_weight(&mut self, pi_weight: Vec<f64>){
+        self.pi_weight = pi_weight;
+    }
+}
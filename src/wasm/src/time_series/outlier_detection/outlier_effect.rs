@@ -0,0 +1,72 @@
+// Rate of decay of a temporary change
+pub const TC_DELTA: f64 = 0.7;
+
+// Multiply two polynomials in the backshift operator
+fn multiply_polynomial(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result: Vec<f64> = vec![0.0; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            result[i + j] += x * y;
+        }
+    }
+    result
+}
+
+// First m weights of pi(B) = phi(B)(1 - B)^d / theta(B), so that e_t = pi(B) y_t
+pub fn pi_weights(ar_coef: &[f64], ma_coef: &[f64], d: usize, m: usize) -> Vec<f64> {
+    let mut numerator: Vec<f64> = vec![1.0];
+    numerator.extend(ar_coef.iter().map(|phi| -phi));
+    for _ in 0..d {
+        numerator = multiply_polynomial(&numerator, &[1.0, -1.0]);
+    }
+    let mut pi: Vec<f64> = Vec::new();
+    for j in 0..m {
+        let mut value = if j < numerator.len() { numerator[j] } else { 0.0 };
+        for i in 1..=ma_coef.len().min(j) {
+            value -= ma_coef[i - 1] * pi[j - i];
+        }
+        pi.push(value);
+    }
+    pi
+}
+
+// First m weights of psi(B) = theta(B) / (phi(B)(1 - B)^d), the inverse of pi(B)
+pub fn psi_weights(pi: &[f64], m: usize) -> Vec<f64> {
+    let mut psi: Vec<f64> = Vec::new();
+    for j in 0..m {
+        let mut value = if j == 0 { 1.0 } else { 0.0 };
+        for i in 1..=j.min(pi.len() - 1) {
+            value -= pi[i] * psi[j - i];
+        }
+        psi.push(value);
+    }
+    psi
+}
+
+// Pattern of an outlier on the series, starting at its date
+pub fn outlier_pattern(outlier_type: &str, pi: &[f64], m: usize) -> Vec<f64> {
+    match outlier_type {
+        "AO" => (0..m).map(|j| if j == 0 { 1.0 } else { 0.0 }).collect(),
+        "IO" => psi_weights(pi, m),
+        "LS" => vec![1.0; m],
+        "TC" => (0..m).map(|j| TC_DELTA.powi(j as i32)).collect(),
+        _ => panic!("Unknown outlier type: {}", outlier_type),
+    }
+}
+
+// Effect of a unit outlier on the residuals, x_j = pi(B) applied to the pattern
+pub fn outlier_effect(outlier_type: &str, pi: &[f64], m: usize) -> Vec<f64> {
+    if outlier_type == "IO" {
+        return (0..m).map(|j| if j == 0 { 1.0 } else { 0.0 }).collect();
+    }
+    let pattern: Vec<f64> = outlier_pattern(outlier_type, pi, m);
+    let mut effect: Vec<f64> = Vec::new();
+    for j in 0..m {
+        let mut value = 0.0;
+        for i in 0..=j {
+            value += pi[i] * pattern[j - i];
+        }
+        effect.push(value);
+    }
+    effect
+}
@@ -0,0 +1,120 @@
+use wasm_bindgen::prelude::*;
+use js_sys::{Object, Reflect};
+use nalgebra::{DMatrix, DVector};
+use crate::{Arima, OutlierDetection};
+use crate::time_series::outlier_detection::outlier_effect::outlier_pattern;
+
+const MAX_ITERATION: usize = 20;
+
+// Regression with ARIMA errors on the intervention dummies: the ARIMA model of the series
+// with the outlier effects removed, and the effects with their standard errors and t-statistics
+pub struct InterventionFit {
+    pub arima: Arima,
+    pub effect: Vec<f64>,
+    pub standard_error: Vec<f64>,
+    pub t_stat: Vec<f64>,
+    pub iteration: usize,
+}
+
+// Residuals of a series under fixed ARIMA coefficients, aligned with the series
+fn fixed_residual(data: Vec<f64>, fit: &Arima, constant: f64) -> Vec<f64> {
+    let d = fit.get_i_order() as usize;
+    let mut arima = Arima::new(data, fit.get_ar_order(), fit.get_i_order(), fit.get_ma_order());
+    arima.set_constant(constant);
+    arima.set_ar_coef(fit.get_ar_coef());
+    arima.set_ma_coef(fit.get_ma_coef());
+    let mut aligned: Vec<f64> = vec![0.0; d];
+    aligned.extend(arima.estimate_residual());
+    aligned
+}
+
+impl OutlierDetection{
+    // Intervention dummies of the detected outliers, one column per outlier, with the IO
+    // pattern taken from the pi weights of the detection stage
+    pub fn intervention_regressors(&self) -> Vec<Vec<f64>>{
+        let n = self.get_data().len();
+        let pi: Vec<f64> = self.get_pi_weight();
+        if pi.len() < n && !self.get_outlier_index().is_empty() {
+            panic!("Outliers must be detected before building the intervention regressors");
+        }
+        self.get_outlier_type().iter().zip(self.get_outlier_index().iter()).map(|(outlier_type, t0)| {
+            let mut column: Vec<f64> = vec![0.0; n];
+            column[*t0..].copy_from_slice(&outlier_pattern(outlier_type, &pi, n - t0));
+            column
+        }).collect()
+    }
+
+    // Joint estimation by iterated feasible GLS. For fixed ARMA coefficients the residuals
+    // are linear in the constant and the effects, e = e(y) - c e(1) - sum omega_k e(x_k), so
+    // these are least squares coefficients on the filtered regressors; the ARIMA model is then
+    // re-estimated on the series less the effects until the effects settle
+    pub fn refit_intervention(&self) -> InterventionFit{
+        let data: Vec<f64> = self.get_data();
+        let n = data.len();
+        let regressors: Vec<Vec<f64>> = self.intervention_regressors();
+        let k = regressors.len();
+        let start = (self.get_i_order() + self.get_ar_order()) as usize;
+        let mut effect: Vec<f64> = self.get_magnitude();
+        let remove_effects = |effect: &[f64]| -> Vec<f64> {
+            (0..n).map(|t| data[t] - (0..k).map(|c| effect[c] * regressors[c][t]).sum::<f64>()).collect()
+        };
+
+        let mut arima = Arima::new(remove_effects(&effect), self.get_ar_order(), self.get_i_order(), self.get_ma_order());
+        arima.estimate_coef();
+        let (mut standard_error, mut t_stat) = (vec![f64::NAN; k], vec![f64::NAN; k]);
+        let mut iteration = 0;
+        while k > 0 && iteration < MAX_ITERATION {
+            iteration += 1;
+            let residual: Vec<f64> = fixed_residual(data.clone(), &arima, 0.0);
+            let mut filtered: Vec<Vec<f64>> = vec![fixed_residual(vec![0.0; n], &arima, 1.0).iter().map(|e| -e).collect()];
+            filtered.extend(regressors.iter().map(|x| fixed_residual(x.clone(), &arima, 0.0)));
+            let rows = n - start;
+            let x = DMatrix::from_fn(rows, k + 1, |i, c| filtered[c][start + i]);
+            let y = DVector::from_column_slice(&residual[start..]);
+            let xtx_inv = (x.transpose() * &x).pseudo_inverse(1e-12).expect("Intervention regression failed");
+            let coefficient = &xtx_inv * x.transpose() * &y;
+            let fitted = &x * &coefficient;
+            let beta: Vec<f64> = coefficient.iter().skip(1).cloned().collect();
+            let sse: f64 = (0..rows).map(|i| (y[i] - fitted[i]).powi(2)).sum();
+            let sigma2 = sse / (rows as f64 - k as f64 - 1.0).max(1.0);
+            standard_error = (0..k).map(|c| (sigma2 * xtx_inv[(c + 1, c + 1)]).sqrt()).collect();
+            t_stat = (0..k).map(|c| beta[c] / standard_error[c]).collect();
+
+            let change = (0..k).fold(0.0f64, |m, c| m.max((beta[c] - effect[c]).abs() / (1.0 + effect[c].abs())));
+            effect = beta;
+            arima = Arima::new(remove_effects(&effect), self.get_ar_order(), self.get_i_order(), self.get_ma_order());
+            arima.estimate_coef();
+            if change < 1e-6 {
+                break;
+            }
+        }
+        InterventionFit { arima, effect, standard_error, t_stat, iteration }
+    }
+}
+
+#[wasm_bindgen]
+impl OutlierDetection{
+    // Intervention dummies of the detected outliers, one column per outlier
+    pub fn calculate_intervention_regressors(&self) -> JsValue{
+        serde_wasm_bindgen::to_value(&self.intervention_regressors()).unwrap()
+    }
+
+    // Refit the ARIMA model jointly with the intervention dummies: the ARMA coefficients with
+    // the re-estimated outlier effects, their standard errors and t-statistics
+    pub fn refit_arima(&self) -> JsValue{
+        let fit = self.refit_intervention();
+        let results = Object::new();
+        let values = |v: &Vec<f64>| serde_wasm_bindgen::to_value(v).unwrap();
+        Reflect::set(&results, &"Constant".into(), &fit.arima.get_constant().into()).unwrap();
+        Reflect::set(&results, &"AR".into(), &values(&fit.arima.get_ar_coef())).unwrap();
+        Reflect::set(&results, &"MA".into(), &values(&fit.arima.get_ma_coef())).unwrap();
+        Reflect::set(&results, &"Residual Variance".into(), &fit.arima.res_variance().into()).unwrap();
+        Reflect::set(&results, &"Outlier Type".into(), &serde_wasm_bindgen::to_value(&self.get_outlier_type()).unwrap()).unwrap();
+        Reflect::set(&results, &"Outlier Index".into(), &serde_wasm_bindgen::to_value(&self.get_outlier_index()).unwrap()).unwrap();
+        Reflect::set(&results, &"Effect".into(), &values(&fit.effect)).unwrap();
+        Reflect::set(&results, &"Std. Error".into(), &values(&fit.standard_error)).unwrap();
+        Reflect::set(&results, &"t".into(), &values(&fit.t_stat)).unwrap();
+        Reflect::set(&results, &"Iterations".into(), &(fit.iteration as f64).into()).unwrap();
+        JsValue::from(results)
+    }
+}
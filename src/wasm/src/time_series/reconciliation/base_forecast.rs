@@ -0,0 +1,28 @@
+use crate::Reconciliation;
+use crate::time_series::forecast_combination::forecaster::Forecaster;
+
+impl Reconciliation {
+    // Base forecasts of every node from its own forecaster, in the order of the nodes. The
+    // one-step errors over the last window observations (refitting at every origin) and
+    // the observed series are kept for MinT-shrink and top-down reconciliation
+    pub fn set_base_from_forecasters(&mut self, models: &[&dyn Forecaster], horizon: usize, window: usize) {
+        if models.len() != self.get_node().len() {
+            panic!("Reconciliation needs one forecaster per node");
+        }
+        let mut base = Vec::new();
+        let mut residual = Vec::new();
+        let mut history = Vec::new();
+        for model in models {
+            let series = model.series();
+            if window >= series.len() - 1 {
+                panic!("Residual window must leave at least two observations to fit on");
+            }
+            base.push(model.forecast(horizon).0);
+            residual.push((series.len() - window..series.len()).map(|t| series[t] - model.forecast_from(t, 1).0[0]).collect());
+            history.push(series);
+        }
+        self.set_base_forecast_values(base);
+        self.set_residual_values(residual);
+        self.set_history_values(history);
+    }
+}
@@ -0,0 +1,35 @@
+use nalgebra::DMatrix;
+
+// Shrinkage estimate of the one-step error covariance (Schafer-Strimmer intensity towards
+// the diagonal, as used by MinT-shrink). The residuals are taken as mean zero; periods with
+// a missing residual in any node are dropped. Returns the covariance and the intensity
+pub fn shrinkage_covariance(residual: &[Vec<f64>]) -> (DMatrix<f64>, f64) {
+    let k = residual.len();
+    let periods: Vec<usize> = (0..residual.first().map_or(0, |r| r.len())).filter(|t| residual.iter().all(|r| r[*t].is_finite())).collect();
+    let n = periods.len();
+    if n < 2 {
+        panic!("MinT-shrink needs residuals of at least two periods");
+    }
+    let x = DMatrix::from_fn(n, k, |t, i| residual[i][periods[t]]);
+    let covariance = x.transpose() * &x / n as f64;
+    let sd: Vec<f64> = (0..k).map(|i| covariance[(i, i)].sqrt()).collect();
+    let xs = DMatrix::from_fn(n, k, |t, i| if sd[i] > 0.0 { x[(t, i)] / sd[i] } else { 0.0 });
+
+    // Variance of the sample correlations against their squared size
+    let xs2 = xs.map(|v| v * v);
+    let cross = xs.transpose() * &xs;
+    let cross2 = xs2.transpose() * &xs2;
+    let mut variance = 0.0;
+    let mut size = 0.0;
+    for i in 0..k {
+        for j in 0..k {
+            if i != j {
+                variance += (cross2[(i, j)] - cross[(i, j)].powi(2) / n as f64) / (n as f64 * (n as f64 - 1.0));
+                size += (cross[(i, j)] / n as f64).powi(2);
+            }
+        }
+    }
+    let lambda = if size > 0.0 { (variance / size).clamp(0.0, 1.0) } else { 1.0 };
+    let shrunk = DMatrix::from_fn(k, k, |i, j| if i == j { covariance[(i, i)] } else { (1.0 - lambda) * covariance[(i, j)] });
+    (shrunk, lambda)
+}
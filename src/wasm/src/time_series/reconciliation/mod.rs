@@ -0,0 +1,7 @@
+pub mod reconciliation;
+pub mod summing_matrix;
+pub mod mint_shrink;
+pub mod reconcile;
+pub mod base_forecast;
+
+pub use reconciliation::Reconciliation;
\ No newline at end of file
@@ -0,0 +1,97 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DMatrix;
+use crate::Reconciliation;
+use crate::time_series::reconciliation::summing_matrix::{bottom_rows, top_row};
+use crate::time_series::reconciliation::mint_shrink::shrinkage_covariance;
+
+fn to_matrix(rows: &[Vec<f64>]) -> DMatrix<f64> {
+    DMatrix::from_fn(rows.len(), rows.first().map_or(0, |r| r.len()), |i, j| rows[i][j])
+}
+
+fn to_rows(matrix: &DMatrix<f64>) -> Vec<Vec<f64>> {
+    (0..matrix.nrows()).map(|i| matrix.row(i).iter().cloned().collect()).collect()
+}
+
+// G = (S' W^-1 S)^-1 S' W^-1 of the generalized least squares reconciliation
+fn gls_mapping(s: &DMatrix<f64>, w: &DMatrix<f64>) -> DMatrix<f64> {
+    let w_inverse = match w.clone().try_inverse() {
+        Some(inverse) => inverse,
+        None => panic!("Error covariance of the reconciliation is singular"),
+    };
+    let st_w = s.transpose() * w_inverse;
+    match (&st_w * s).try_inverse() {
+        Some(inverse) => inverse * st_w,
+        None => panic!("Summing matrix does not have full column rank"),
+    }
+}
+
+impl Reconciliation {
+    // Mapping matrix G from the base forecasts of all nodes to the bottom level series
+    pub fn mapping(&mut self, method: &str) -> DMatrix<f64> {
+        let rows = self.get_summing_matrix_values();
+        let s = to_matrix(&rows);
+        let (n, m) = (s.nrows(), s.ncols());
+        match method {
+            "bottom_up" => {
+                let bottom = bottom_rows(&rows);
+                DMatrix::from_fn(m, n, |j, i| if bottom[j] == i { 1.0 } else { 0.0 })
+            }
+            "top_down" => {
+                // Average historical proportions of the bottom series in the total
+                let history = self.get_history_values();
+                if history.is_empty() {
+                    panic!("Top-down reconciliation needs the history of every node");
+                }
+                let (bottom, top) = (bottom_rows(&rows), top_row(&rows));
+                let periods: Vec<usize> = (0..history[top].len()).filter(|t| history[top][*t] != 0.0 && history[top][*t].is_finite()).collect();
+                if periods.is_empty() {
+                    panic!("Top-down reconciliation needs a nonzero total");
+                }
+                let proportion: Vec<f64> = bottom
+                    .iter()
+                    .map(|b| periods.iter().map(|t| history[*b][*t] / history[top][*t]).sum::<f64>() / periods.len() as f64)
+                    .collect();
+                DMatrix::from_fn(m, n, |j, i| if i == top { proportion[j] } else { 0.0 })
+            }
+            "ols" => gls_mapping(&s, &DMatrix::identity(n, n)),
+            "wls_structural" => {
+                // Variances proportional to the number of bottom series in each node
+                let count: Vec<f64> = (0..n).map(|i| s.row(i).sum()).collect();
+                gls_mapping(&s, &DMatrix::from_fn(n, n, |i, j| if i == j { count[i] } else { 0.0 }))
+            }
+            "mint_shrink" => {
+                let residual = self.get_residual_values();
+                if residual.is_empty() {
+                    panic!("MinT-shrink reconciliation needs the in-sample residuals of every node");
+                }
+                let (w, lambda) = shrinkage_covariance(&residual);
+                self.set_shrinkage(lambda);
+                gls_mapping(&s, &w)
+            }
+            _ => panic!("Unknown reconciliation method: {}", method),
+        }
+    }
+
+    // Coherent forecasts S * G * base forecasts, one row per node
+    pub fn reconcile(&mut self, method: &str) -> Vec<Vec<f64>> {
+        let base = self.get_base_forecast_values();
+        if base.is_empty() {
+            panic!("Reconciliation needs base forecasts of every node");
+        }
+        let g = self.mapping(method);
+        let s = to_matrix(&self.get_summing_matrix_values());
+        let reconciled = to_rows(&(&s * &g * to_matrix(&base)));
+        self.set_mapping_matrix_values(to_rows(&g));
+        self.set_reconciled_forecast_values(reconciled.clone());
+        reconciled
+    }
+}
+
+#[wasm_bindgen]
+impl Reconciliation {
+    // Reconcile by "bottom_up", "top_down" (average historical proportions), "ols",
+    // "wls_structural" or "mint_shrink"; returns one row of coherent forecasts per node
+    pub fn calculate_reconciliation(&mut self, method: String) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.reconcile(&method)).unwrap()
+    }
+}
@@ -0,0 +1,116 @@
+use wasm_bindgen::prelude::*;
+use serde_wasm_bindgen::{to_value, from_value};
+
+#[wasm_bindgen]
+pub struct Reconciliation {
+    node: Vec<String>,
+    summing_matrix: Vec<Vec<f64>>,      // One row per node, one column per bottom level series
+    base_forecast: Vec<Vec<f64>>,       // One row of forecasts per node
+    residual: Vec<Vec<f64>>,            // In-sample one-step errors per node, for MinT
+    history: Vec<Vec<f64>>,             // Observed series per node, for top-down proportions
+    reconciled_forecast: Vec<Vec<f64>>,
+    mapping_matrix: Vec<Vec<f64>>,      // G in S * G * base forecasts
+    shrinkage: f64,                     // MinT-shrink intensity towards the diagonal covariance
+}
+
+#[wasm_bindgen]
+impl Reconciliation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(node: Vec<String>, summing_matrix: JsValue, base_forecast: JsValue) -> Reconciliation {
+        Reconciliation::from_matrix(node, from_value(summing_matrix).unwrap(), from_value(base_forecast).unwrap())
+    }
+
+    // Getters
+    pub fn get_node(&self) -> Vec<String> {
+        self.node.clone()
+    }
+    pub fn get_summing_matrix(&self) -> JsValue {
+        to_value(&self.summing_matrix).unwrap()
+    }
+    pub fn get_base_forecast(&self) -> JsValue {
+        to_value(&self.base_forecast).unwrap()
+    }
+    pub fn get_reconciled_forecast(&self) -> JsValue {
+        to_value(&self.reconciled_forecast).unwrap()
+    }
+    pub fn get_mapping_matrix(&self) -> JsValue {
+        to_value(&self.mapping_matrix).unwrap()
+    }
+    pub fn get_shrinkage(&self) -> f64 {
+        self.shrinkage
+    }
+
+    // Setters
+    pub fn set_base_forecast(&mut self, base_forecast: JsValue) {
+        self.set_base_forecast_values(from_value(base_forecast).unwrap());
+    }
+    pub fn set_residual(&mut self, residual: JsValue) {
+        self.set_residual_values(from_value(residual).unwrap());
+    }
+    pub fn set_history(&mut self, history: JsValue) {
+        self.set_history_values(from_value(history).unwrap());
+    }
+    pub fn set_shrinkage(&mut self, shrinkage: f64) {
+        self.shrinkage = shrinkage;
+    }
+}
+
+impl Reconciliation {
+    pub fn from_matrix(node: Vec<String>, summing_matrix: Vec<Vec<f64>>, base_forecast: Vec<Vec<f64>>) -> Reconciliation {
+        if summing_matrix.len() != node.len() {
+            panic!("Summing matrix needs one row per node");
+        }
+        let mut reconciliation = Reconciliation {
+            node,
+            summing_matrix,
+            base_forecast: Vec::new(),
+            residual: Vec::new(),
+            history: Vec::new(),
+            reconciled_forecast: Vec::new(),
+            mapping_matrix: Vec::new(),
+            shrinkage: f64::NAN,
+        };
+        reconciliation.set_base_forecast_values(base_forecast);
+        reconciliation
+    }
+
+    pub fn get_summing_matrix_values(&self) -> Vec<Vec<f64>> {
+        self.summing_matrix.clone()
+    }
+    pub fn get_base_forecast_values(&self) -> Vec<Vec<f64>> {
+        self.base_forecast.clone()
+    }
+    pub fn get_residual_values(&self) -> Vec<Vec<f64>> {
+        self.residual.clone()
+    }
+    pub fn get_history_values(&self) -> Vec<Vec<f64>> {
+        self.history.clone()
+    }
+    pub fn get_reconciled_forecast_values(&self) -> Vec<Vec<f64>> {
+        self.reconciled_forecast.clone()
+    }
+
+    fn check_rows(&self, rows: &[Vec<f64>], name: &str) {
+        if !rows.is_empty() && rows.len() != self.node.len() {
+            panic!("{} needs one row per node", name);
+        }
+    }
+    pub fn set_base_forecast_values(&mut self, base_forecast: Vec<Vec<f64>>) {
+        self.check_rows(&base_forecast, "Base forecast");
+        self.base_forecast = base_forecast;
+    }
+    pub fn set_residual_values(&mut self, residual: Vec<Vec<f64>>) {
+        self.check_rows(&residual, "Residual");
+        self.residual = residual;
+    }
+    pub fn set_history_values(&mut self, history: Vec<Vec<f64>>) {
+        self.check_rows(&history, "History");
+        self.history = history;
+    }
+    pub fn set_reconciled_forecast_values(&mut self, reconciled_forecast: Vec<Vec<f64>>) {
+        self.reconciled_forecast = reconciled_forecast;
+    }
+    pub fn set_mapping_matrix_values(&mut self, mapping_matrix: Vec<Vec<f64>>) {
+        self.mapping_matrix = mapping_matrix;
+    }
+}
@@ -0,0 +1,57 @@
+use wasm_bindgen::prelude::*;
+
+// Summing matrix of a hierarchy given the parent of every node ("" for the top).
+// The bottom level series are the nodes without children, in the order given
+pub fn hierarchy_summing_matrix(node: &[String], parent: &[String]) -> Vec<Vec<f64>> {
+    if node.len() != parent.len() {
+        panic!("Every node needs a parent");
+    }
+    let position = |name: &str| node.iter().position(|n| n == name);
+    for p in parent.iter().filter(|p| !p.is_empty()) {
+        if position(p).is_none() {
+            panic!("Unknown parent node: {}", p);
+        }
+    }
+    let bottom: Vec<usize> = (0..node.len()).filter(|i| !parent.contains(&node[*i])).collect();
+    let mut matrix = vec![vec![0.0; bottom.len()]; node.len()];
+    for (j, leaf) in bottom.iter().enumerate() {
+        // Walk from the bottom series up to the top
+        let mut current = Some(*leaf);
+        let mut steps = 0;
+        while let Some(i) = current {
+            matrix[i][j] = 1.0;
+            current = position(&parent[i]);
+            steps += 1;
+            if steps > node.len() {
+                panic!("Hierarchy contains a cycle at node {}", node[*leaf]);
+            }
+        }
+    }
+    matrix
+}
+
+#[wasm_bindgen]
+pub fn summing_matrix(node: Vec<String>, parent: Vec<String>) -> JsValue {
+    serde_wasm_bindgen::to_value(&hierarchy_summing_matrix(&node, &parent)).unwrap()
+}
+
+// Row of every bottom level series, i.e. the unit rows of the summing matrix
+pub fn bottom_rows(summing_matrix: &[Vec<f64>]) -> Vec<usize> {
+    let m = summing_matrix.first().map_or(0, |row| row.len());
+    (0..m)
+        .map(|j| {
+            match summing_matrix.iter().position(|row| row.iter().enumerate().all(|(k, s)| *s == if k == j { 1.0 } else { 0.0 })) {
+                Some(i) => i,
+                None => panic!("Summing matrix has no row for bottom series {}", j + 1),
+            }
+        })
+        .collect()
+}
+
+// Row of the total, the row of ones
+pub fn top_row(summing_matrix: &[Vec<f64>]) -> usize {
+    match summing_matrix.iter().position(|row| row.iter().all(|s| *s == 1.0)) {
+        Some(i) => i,
+        None => panic!("Summing matrix has no total row"),
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+use wasm_bindgen::prelude::*;
+use crate::{Resample, TimeIndex};
+use crate::time_series::resample::period_key::*;
+
+#[wasm_bindgen]
+impl Resample {
+    // Aggregate to a coarser frequency; partial periods are kept ("keep"), removed ("drop") or have their sums scaled up ("prorate")
+    pub fn calculate_aggregation(&mut self, target_frequency: String, method: String, partial: String) -> Vec<f64> {
+        if !["sum", "mean", "first", "last", "min", "max"].contains(&method.as_str()) {
+            panic!("Unknown aggregation method: {}", method);
+        }
+        let data = self.get_data();
+        let time_index = TimeIndex::new(self.get_time(), self.get_time_header());
+        if data.len() != time_index.get_time().len() {
+            panic!("Data and time labels must have the same length");
+        }
+        if ["unknown", "cyclic", "index"].contains(&time_index.get_frequency().as_str()) {
+            panic!("Resampling needs calendar time labels");
+        }
+        let step = time_index.get_step() as i64;
+        let ordinal = time_index.get_ordinal().clone();
+        let key_of = |o: i64| period_key(&target_frequency, source_minute(&time_index, o));
+        let key_step = period_key_step(&target_frequency);
+        if ordinal.iter().any(|o| key_of(o + step) - key_of(*o) > key_step) {
+            panic!("Target frequency must not be finer than the data");
+        }
+
+        // Expected observations per period, counted on the grid from the start of the first period to the end of the last
+        let first = *ordinal.iter().min().unwrap();
+        let last = *ordinal.iter().max().unwrap();
+        let mut start = first;
+        while key_of(start - step) == key_of(first) {
+            start -= step;
+        }
+        let mut end = last;
+        while key_of(end + step) == key_of(last) {
+            end += step;
+        }
+        let mut periods: BTreeMap<i64, (i32, Vec<(i64, f64)>)> = BTreeMap::new();
+        let mut o = start;
+        while o <= end {
+            periods.entry(key_of(o)).or_insert((0, Vec::new())).0 += 1;
+            o += step;
+        }
+        for (i, o) in ordinal.iter().enumerate() {
+            if !data[i].is_nan() {
+                periods.entry(key_of(*o)).or_insert((0, Vec::new())).1.push((*o, data[i]));
+            }
+        }
+
+        let mut resampled_data: Vec<f64> = Vec::new();
+        let mut resampled_time: Vec<String> = Vec::new();
+        let mut observation_count: Vec<i32> = Vec::new();
+        let mut expected_count: Vec<i32> = Vec::new();
+        for (key, (expected, mut values)) in periods {
+            let observed = values.len() as i32;
+            let complete = observed >= expected;
+            match partial.as_str() {
+                "keep" | "prorate" => {}
+                "drop" if complete => {}
+                "drop" => continue,
+                _ => panic!("Unknown partial period handling: {}", partial),
+            }
+            values.sort_by_key(|v| v.0);
+            let x: Vec<f64> = values.iter().map(|v| v.1).collect();
+            let value = if x.is_empty() {
+                f64::NAN
+            } else {
+                match method.as_str() {
+                    "sum" if partial == "prorate" => x.iter().sum::<f64>() * expected as f64 / observed as f64,
+                    "sum" => x.iter().sum(),
+                    "mean" => x.iter().sum::<f64>() / x.len() as f64,
+                    "first" => x[0],
+                    "last" => x[x.len() - 1],
+                    "min" => x.iter().copied().fold(f64::INFINITY, f64::min),
+                    _ => x.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                }
+            };
+            resampled_data.push(value);
+            resampled_time.push(format_period(&target_frequency, key));
+            observation_count.push(observed);
+            expected_count.push(expected);
+        }
+
+        self.set_resampled_time(resampled_time);
+        self.set_observation_count(observation_count);
+        self.set_expected_count(expected_count);
+        self.set_resampled_data(resampled_data.clone());
+        resampled_data
+    }
+}
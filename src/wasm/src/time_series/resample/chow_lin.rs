@@ -0,0 +1,63 @@
+use nalgebra::{DMatrix, DVector};
+use wasm_bindgen::prelude::*;
+use crate::Resample;
+
+// Log-likelihood, coefficients, residual covariance and weighted residuals of a GLS fit
+type GlsFit = (f64, DVector<f64>, DMatrix<f64>, DVector<f64>);
+
+#[wasm_bindgen]
+impl Resample {
+    // Chow-Lin: GLS regression on the indicator with AR(1) residuals, rho chosen by maximum likelihood
+    pub fn calculate_chow_lin(&mut self, target_frequency: String, conversion: String, indicator: Vec<f64>) -> Vec<f64> {
+        let (time, aggregation) = self.disaggregation_frame(&target_frequency, &conversion);
+        let n = aggregation.ncols();
+        let nl = aggregation.nrows();
+        if indicator.len() != n {
+            panic!("Indicator must have {} values", n);
+        }
+        if nl < 3 {
+            panic!("Chow-Lin needs at least 3 observations");
+        }
+        let x = DMatrix::from_fn(n, 2, |i, j| if j == 0 { 1.0 } else { indicator[i] });
+        let cx = &aggregation * &x;
+        let y = DVector::from_vec(self.get_data());
+
+        // GLS fit for a given rho
+        let fit = |rho: f64| -> Option<GlsFit> {
+            let sigma = DMatrix::from_fn(n, n, |i, j| rho.powi((i as i32 - j as i32).abs()) / (1.0 - rho * rho));
+            let v = &aggregation * &sigma * aggregation.transpose();
+            let cholesky = v.cholesky()?;
+            let log_det: f64 = 2.0 * cholesky.l().diagonal().iter().map(|d| d.ln()).sum::<f64>();
+            let v_inv = cholesky.inverse();
+            let xtv = cx.transpose() * &v_inv;
+            let beta = (&xtv * &cx).try_inverse()? * &xtv * &y;
+            let residual = &y - &cx * &beta;
+            let weighted = &v_inv * &residual;
+            let s2 = residual.dot(&weighted) / nl as f64;
+            let log_likelihood = -0.5 * (nl as f64 * s2.ln() + log_det);
+            Some((log_likelihood, beta, sigma, weighted))
+        };
+
+        let mut best: Option<(f64, f64)> = None;
+        for k in 0..100 {
+            let rho = k as f64 / 100.0;
+            if let Some((log_likelihood, _, _, _)) = fit(rho) {
+                if best.is_none_or(|b| log_likelihood > b.1) {
+                    best = Some((rho, log_likelihood));
+                }
+            }
+        }
+        let rho = best.expect("Chow-Lin regression could not be fitted").0;
+        let (_, beta, sigma, weighted) = fit(rho).unwrap();
+        let estimate = &x * &beta + sigma * aggregation.transpose() * weighted;
+
+        let resampled_data: Vec<f64> = estimate.iter().copied().collect();
+        self.set_rho(rho);
+        self.set_coefficient(beta.iter().copied().collect());
+        self.set_resampled_time(time);
+        self.set_observation_count(Vec::new());
+        self.set_expected_count(Vec::new());
+        self.set_resampled_data(resampled_data.clone());
+        resampled_data
+    }
+}
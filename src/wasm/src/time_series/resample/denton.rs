@@ -0,0 +1,56 @@
+use nalgebra::{DMatrix, DVector};
+use wasm_bindgen::prelude::*;
+use crate::Resample;
+
+#[wasm_bindgen]
+impl Resample {
+    // Denton benchmarking: follow the movement of the indicator (a straight line when empty) while matching the data
+    pub fn calculate_denton(&mut self, target_frequency: String, conversion: String, indicator: Vec<f64>, method: String, order: usize) -> Vec<f64> {
+        let (time, aggregation) = self.disaggregation_frame(&target_frequency, &conversion);
+        let n = aggregation.ncols();
+        let nl = aggregation.nrows();
+        let indicator = if indicator.is_empty() { vec![0.0; n] } else { indicator };
+        if indicator.len() != n {
+            panic!("Indicator must have {} values", n);
+        }
+        if order == 0 || order >= n {
+            panic!("Difference order must be between 1 and {}", n - 1);
+        }
+
+        // Differencing matrix of the requested order
+        let mut difference: DMatrix<f64> = DMatrix::identity(n, n);
+        for _ in 0..order {
+            let rows = difference.nrows();
+            difference = difference.rows(1, rows - 1) - difference.rows(0, rows - 1);
+        }
+        let penalty = match method.as_str() {
+            "additive" => difference.transpose() * &difference,
+            "proportional" => {
+                if indicator.contains(&0.0) {
+                    panic!("Proportional Denton needs a nonzero indicator");
+                }
+                let weight = DMatrix::from_diagonal(&DVector::from_iterator(n, indicator.iter().map(|x| 1.0 / x)));
+                &weight * difference.transpose() * &difference * &weight
+            }
+            _ => panic!("Unknown Denton method: {}", method),
+        };
+
+        // Minimise the penalty on x - indicator subject to aggregation * x = data
+        let indicator = DVector::from_vec(indicator);
+        let mut system: DMatrix<f64> = DMatrix::zeros(n + nl, n + nl);
+        system.view_mut((0, 0), (n, n)).copy_from(&penalty);
+        system.view_mut((0, n), (n, nl)).copy_from(&aggregation.transpose());
+        system.view_mut((n, 0), (nl, n)).copy_from(&aggregation);
+        let mut rhs: DVector<f64> = DVector::zeros(n + nl);
+        rhs.rows_mut(0, n).copy_from(&(&penalty * &indicator));
+        rhs.rows_mut(n, nl).copy_from(&DVector::from_vec(self.get_data()));
+        let solution = system.lu().solve(&rhs).expect("Denton system is singular");
+
+        let resampled_data: Vec<f64> = solution.rows(0, n).iter().copied().collect();
+        self.set_resampled_time(time);
+        self.set_observation_count(Vec::new());
+        self.set_expected_count(Vec::new());
+        self.set_resampled_data(resampled_data.clone());
+        resampled_data
+    }
+}
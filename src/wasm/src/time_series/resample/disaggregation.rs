@@ -0,0 +1,49 @@
+use nalgebra::DMatrix;
+use crate::{Resample, TimeIndex};
+use crate::time_series::resample::period_key::*;
+
+impl Resample {
+    // High frequency labels covering the data and the matrix aggregating them back to it
+    pub fn disaggregation_frame(&self, target_frequency: &str, conversion: &str) -> (Vec<String>, DMatrix<f64>) {
+        let data = self.get_data();
+        let time_index = TimeIndex::new(self.get_time(), self.get_time_header());
+        if data.len() != time_index.get_time().len() {
+            panic!("Data and time labels must have the same length");
+        }
+        if data.iter().any(|x| x.is_nan()) {
+            panic!("Disaggregation needs a series without missing values");
+        }
+        if !time_index.is_regular() || ["cyclic", "index"].contains(&time_index.get_frequency().as_str()) {
+            panic!("Disaggregation needs regular calendar time labels");
+        }
+
+        let key_step = period_key_step(target_frequency);
+        let mut time: Vec<String> = Vec::new();
+        let mut ratio = 0;
+        for o in time_index.get_ordinal() {
+            let (start, end) = time_index.day_span(*o);
+            let first = period_key(target_frequency, start * 1440);
+            let count = (period_key(target_frequency, end * 1440) - first) / key_step;
+            if ratio == 0 {
+                ratio = count;
+            }
+            if count != ratio || count < 2 {
+                panic!("Disaggregation needs a fixed number of finer periods in each observation");
+            }
+            time.extend((0..count).map(|j| format_period(target_frequency, first + j * key_step)));
+        }
+
+        let s = ratio as usize;
+        let mut aggregation: DMatrix<f64> = DMatrix::zeros(data.len(), data.len() * s);
+        for i in 0..data.len() {
+            match conversion {
+                "sum" => aggregation.view_mut((i, i * s), (1, s)).fill(1.0),
+                "mean" => aggregation.view_mut((i, i * s), (1, s)).fill(1.0 / s as f64),
+                "first" => aggregation[(i, i * s)] = 1.0,
+                "last" => aggregation[(i, i * s + s - 1)] = 1.0,
+                _ => panic!("Unknown conversion: {}", conversion),
+            }
+        }
+        (time, aggregation)
+    }
+}
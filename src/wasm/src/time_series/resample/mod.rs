@@ -0,0 +1,8 @@
+pub mod resample;
+pub mod period_key;
+pub mod aggregation;
+pub mod disaggregation;
+pub mod denton;
+pub mod chow_lin;
+
+pub use resample::Resample;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use crate::TimeIndex;
+use crate::time_series::time_index::calendar::*;
+
+// Period of the target frequency containing a moment given in minutes since 1970-01-01
+pub fn period_key(frequency: &str, minute: i64) -> i64 {
+    let day = minute.div_euclid(1440);
+    let (y, m, _) = civil_from_days(day);
+    match frequency {
+        "yearly" => y,
+        "quarterly" => y * 4 + (m - 1) / 3,
+        "monthly" => y * 12 + m - 1,
+        "weekly" => day - weekday(day),
+        "daily" => day,
+        "hourly" => minute.div_euclid(60),
+        _ => panic!("Unknown target frequency: {}", frequency),
+    }
+}
+
+// Distance between consecutive keys of a frequency
+pub fn period_key_step(frequency: &str) -> i64 {
+    if frequency == "weekly" { 7 } else { 1 }
+}
+
+// Label of a period, written so that TimeIndex parses it back
+pub fn format_period(frequency: &str, key: i64) -> String {
+    let date = |day: i64| {
+        let (y, m, d) = civil_from_days(day);
+        format!("{:04}-{:02}-{:02}", y, m, d)
+    };
+    match frequency {
+        "yearly" => key.to_string(),
+        "quarterly" => format!("{} Q{}", key.div_euclid(4), key.rem_euclid(4) + 1),
+        "monthly" => format!("{}-{:02}", key.div_euclid(12), key.rem_euclid(12) + 1),
+        "weekly" => {
+            let (y, w) = iso_week(key);
+            format!("{}-W{:02}", y, w)
+        }
+        "daily" => date(key),
+        "hourly" => format!("{} {:02}:00", date(key.div_euclid(24)), key.rem_euclid(24)),
+        _ => panic!("Unknown target frequency: {}", frequency),
+    }
+}
+
+// Start of a labelled period in minutes since 1970-01-01
+pub fn source_minute(time_index: &TimeIndex, ordinal: i64) -> i64 {
+    if time_index.get_unit() == "minute" {
+        ordinal
+    } else {
+        time_index.day_span(ordinal).0 * 1440
+    }
+}
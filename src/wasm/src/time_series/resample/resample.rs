@@ -0,0 +1,98 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Resample {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    resampled_data: Vec<f64>,
+    resampled_time: Vec<String>,
+    observation_count: Vec<i32>, // Observations found in each aggregated period
+    expected_count: Vec<i32>,    // Observations a complete period holds
+    rho: f64,                    // Chow-Lin autocorrelation of the high frequency residuals
+    coefficient: Vec<f64>,       // Chow-Lin constant and indicator coefficient
+}
+
+#[wasm_bindgen]
+impl Resample {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String) -> Resample {
+        Resample {
+            data,
+            data_header,
+            time,
+            time_header,
+            resampled_data: Vec::new(),
+            resampled_time: Vec::new(),
+            observation_count: Vec::new(),
+            expected_count: Vec::new(),
+            rho: 0.0,
+            coefficient: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String {
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String> {
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String {
+        self.time_header.clone()
+    }
+    pub fn get_resampled_data(&self) -> Vec<f64> {
+        self.resampled_data.clone()
+    }
+    pub fn get_resampled_time(&self) -> Vec<String> {
+        self.resampled_time.clone()
+    }
+    pub fn get_observation_count(&self) -> Vec<i32> {
+        self.observation_count.clone()
+    }
+    pub fn get_expected_count(&self) -> Vec<i32> {
+        self.expected_count.clone()
+    }
+    pub fn get_rho(&self) -> f64 {
+        self.rho
+    }
+    pub fn get_coefficient(&self) -> Vec<f64> {
+        self.coefficient.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_data_header(&mut self, data_header: String) {
+        self.data_header = data_header;
+    }
+    pub fn set_time(&mut self, time: Vec<String>) {
+        self.time = time;
+    }
+    pub fn set_time_header(&mut self, time_header: String) {
+        self.time_header = time_header;
+    }
+    pub fn set_resampled_data(&mut self, resampled_data: Vec<f64>) {
+        self.resampled_data = resampled_data;
+    }
+    pub fn set_resampled_time(&mut self, resampled_time: Vec<String>) {
+        self.resampled_time = resampled_time;
+    }
+    pub fn set_observation_count(&mut self, observation_count: Vec<i32>) {
+        self.observation_count = observation_count;
+    }
+    pub fn set_expected_count(&mut self, expected_count: Vec<i32>) {
+        self.expected_count = expected_count;
+    }
+    pub fn set_rho(&mut self, rho: f64) {
+        self.rho = rho;
+    }
+    pub fn set_coefficient(&mut self, coefficient: Vec<f64>) {
+        self.coefficient = coefficient;
+    }
+}
@@ -0,0 +1,37 @@
+use wasm_bindgen::prelude::*;
+use crate::{Autocorrelation, Seasonality, SimpleLinearRegression};
+
+#[wasm_bindgen]
+impl Seasonality {
+    // Remove the linear trend so it does not dominate the ACF and periodogram
+    pub fn calculate_detrended(&self) -> Vec<f64> {
+        let data: Vec<f64> = self.get_data();
+        let mut t: Vec<f64> = Vec::new();
+        for i in 0..data.len() {
+            t.push(i as f64 + 1.0);
+        }
+        let mut regression = SimpleLinearRegression::new(t, data.clone());
+        regression.calculate_regression();
+        let trend: Vec<f64> = regression.get_y_prediction();
+        data.iter().zip(trend.iter()).map(|(y, y_hat)| y - y_hat).collect()
+    }
+
+    // Score every lag by its ACF value when it is a significant local peak
+    pub fn calculate_acf_peaks(&self, detrended: Vec<f64>, max_period: i32) -> Vec<f64> {
+        let n = detrended.len() as f64;
+        let autocorrelation = Autocorrelation::new(detrended.clone(), self.get_data_header(), max_period + 1);
+        let acf: Vec<f64> = autocorrelation.calculate_acf(detrended);
+        let bound = 1.96 / n.sqrt();
+
+        // acf[k - 1] is the autocorrelation at lag k
+        let mut score: Vec<f64> = vec![0.0; max_period as usize + 1];
+        for lag in 2..=max_period as usize {
+            let value = acf[lag - 1];
+            let is_peak = value > acf[lag - 2] && value >= acf[lag];
+            if is_peak && value > bound {
+                score[lag] = value;
+            }
+        }
+        score
+    }
+}
\ No newline at end of file
@@ -0,0 +1,19 @@
+use wasm_bindgen::prelude::*;
+use crate::{Seasonality, TimeIndex};
+
+// Seasonal period implied by the labels of the time vector, 0 when unknown
+pub fn calendar_period(time: Vec<String>) -> i32 {
+    if time.len() < 2 {
+        return 0;
+    }
+    TimeIndex::new(time, String::new()).get_period()
+}
+
+#[wasm_bindgen]
+impl Seasonality {
+    pub fn calculate_calendar_period(&mut self) -> i32 {
+        let period = calendar_period(self.get_time());
+        self.set_calendar_period(period);
+        period
+    }
+}
\ No newline at end of file
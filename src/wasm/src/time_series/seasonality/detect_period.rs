@@ -0,0 +1,61 @@
+use wasm_bindgen::prelude::*;
+use crate::Seasonality;
+
+// Weights of each source in the combined strength score
+const ACF_WEIGHT: f64 = 0.4;
+const PERIODOGRAM_WEIGHT: f64 = 0.4;
+const CALENDAR_WEIGHT: f64 = 0.2;
+
+#[wasm_bindgen]
+impl Seasonality {
+    // Detect candidate seasonal periods ranked by strength
+    pub fn detect_period(&mut self) -> Vec<i32> {
+        let n = self.get_data().len() as i32;
+        let mut max_period = self.get_max_period();
+        if max_period <= 0 || max_period > n / 2 {
+            max_period = n / 2;
+        }
+        if max_period < 2 {
+            self.set_period(Vec::new());
+            self.set_strength(Vec::new());
+            self.set_acf_score(Vec::new());
+            self.set_periodogram_score(Vec::new());
+            return Vec::new();
+        }
+
+        // Score every period from each source
+        let detrended: Vec<f64> = self.calculate_detrended();
+        let acf_score: Vec<f64> = self.calculate_acf_peaks(detrended.clone(), max_period);
+        let periodogram_score: Vec<f64> = self.calculate_periodogram_peaks(detrended, max_period);
+        let calendar_period = self.calculate_calendar_period();
+
+        // Combine the scores into one strength per candidate
+        let mut candidates: Vec<(i32, f64, f64, f64)> = Vec::new();
+        for period in 2..=max_period as usize {
+            let calendar_score = if period as i32 == calendar_period { 1.0 } else { 0.0 };
+            let strength = ACF_WEIGHT * acf_score[period] + PERIODOGRAM_WEIGHT * periodogram_score[period] + CALENDAR_WEIGHT * calendar_score;
+            // A flat or degenerate series gives non-finite scores, which are not candidates
+            if strength.is_finite() && strength > 0.0 {
+                candidates.push((period as i32, strength, acf_score[period], periodogram_score[period]));
+            }
+        }
+
+        // Rank by strength, preferring the shorter period on ties
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+
+        let period: Vec<i32> = candidates.iter().map(|c| c.0).collect();
+        self.set_strength(candidates.iter().map(|c| c.1).collect());
+        self.set_acf_score(candidates.iter().map(|c| c.2).collect());
+        self.set_periodogram_score(candidates.iter().map(|c| c.3).collect());
+        self.set_period(period.clone());
+        period
+    }
+
+    // Strongest candidate period, 0 when no seasonality is detected
+    pub fn get_dominant_period(&self) -> i32 {
+        match self.get_period().first() {
+            Some(period) => *period,
+            None => 0,
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,7 @@
+pub mod seasonality;
+pub mod acf_peaks;
+pub mod periodogram;
+pub mod calendar_frequency;
+pub mod detect_period;
+
+pub use seasonality::Seasonality;
\ No newline at end of file
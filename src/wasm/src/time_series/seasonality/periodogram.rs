@@ -0,0 +1,48 @@
+use wasm_bindgen::prelude::*;
+use crate::Seasonality;
+use std::f64::consts::PI;
+
+// Periodogram ordinate of the demeaned data at a single frequency (cycles per observation)
+pub fn periodogram_at(data: &[f64], frequency: f64) -> f64 {
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let mut re = 0.0;
+    let mut im = 0.0;
+    for (t, x) in data.iter().enumerate() {
+        let angle = 2.0 * PI * frequency * t as f64;
+        re += (x - mean) * angle.cos();
+        im -= (x - mean) * angle.sin();
+    }
+    (re.powi(2) + im.powi(2)) / n
+}
+
+// Raw periodogram at the Fourier frequencies k / n, k = 1..n/2
+pub fn periodogram(data: Vec<f64>) -> Vec<f64> {
+    let n = data.len();
+    (1..=n / 2).map(|k| periodogram_at(&data, k as f64 / n as f64)).collect()
+}
+
+#[wasm_bindgen]
+impl Seasonality {
+    // Score every period by the relative power of its periodogram peak
+    pub fn calculate_periodogram_peaks(&self, detrended: Vec<f64>, max_period: i32) -> Vec<f64> {
+        let n = detrended.len() as f64;
+        let max_power = periodogram(detrended.clone()).iter().cloned().fold(0.0, f64::max);
+        let mut score: Vec<f64> = vec![0.0; max_period as usize + 1];
+        if max_power <= 0.0 {
+            return score;
+        }
+
+        // Compare the power at 1 / period with its neighbours one Fourier step away
+        for (period, period_score) in score.iter_mut().enumerate().skip(2) {
+            let frequency = 1.0 / period as f64;
+            let power = periodogram_at(&detrended, frequency);
+            let left = periodogram_at(&detrended, (frequency - 1.0 / n).max(0.0));
+            let right = periodogram_at(&detrended, (frequency + 1.0 / n).min(0.5));
+            if power > left && power >= right {
+                *period_score = (power / max_power).min(1.0);
+            }
+        }
+        score
+    }
+}
\ No newline at end of file
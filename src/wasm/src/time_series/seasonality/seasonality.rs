@@ -0,0 +1,92 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Seasonality {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    max_period: i32,
+    calendar_period: i32,
+    period: Vec<i32>,          // Ranked candidate periods
+    strength: Vec<f64>,        // Combined strength of each candidate
+    acf_score: Vec<f64>,       // ACF contribution of each candidate
+    periodogram_score: Vec<f64>, // Periodogram contribution of each candidate
+}
+
+#[wasm_bindgen]
+impl Seasonality {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String, max_period: i32) -> Seasonality {
+        Seasonality {
+            data,
+            data_header,
+            time,
+            time_header,
+            max_period,
+            calendar_period: 0,
+            period: Vec::new(),
+            strength: Vec::new(),
+            acf_score: Vec::new(),
+            periodogram_score: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String {
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String> {
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String {
+        self.time_header.clone()
+    }
+    pub fn get_max_period(&self) -> i32 {
+        self.max_period
+    }
+    pub fn get_calendar_period(&self) -> i32 {
+        self.calendar_period
+    }
+    pub fn get_period(&self) -> Vec<i32> {
+        self.period.clone()
+    }
+    pub fn get_strength(&self) -> Vec<f64> {
+        self.strength.clone()
+    }
+    pub fn get_acf_score(&self) -> Vec<f64> {
+        self.acf_score.clone()
+    }
+    pub fn get_periodogram_score(&self) -> Vec<f64> {
+        self.periodogram_score.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_time(&mut self, time: Vec<String>) {
+        self.time = time;
+    }
+    pub fn set_max_period(&mut self, max_period: i32) {
+        self.max_period = max_period;
+    }
+    pub fn set_calendar_period(&mut self, calendar_period: i32) {
+        self.calendar_period = calendar_period;
+    }
+    pub fn set_period(&mut self, period: Vec<i32>) {
+        self.period = period;
+    }
+    pub fn set_strength(&mut self, strength: Vec<f64>) {
+        self.strength = strength;
+    }
+    pub fn set_acf_score(&mut self, acf_score: Vec<f64>) {
+        self.acf_score = acf_score;
+    }
+    pub fn set_periodogram_score(&mut self, periodogram_score: Vec<f64>) {
+        self.periodogram_score = periodogram_score;
+    }
+}
\ No newline at end of file
@@ -1,62 +1,83 @@
 use wasm_bindgen::prelude::*;
 use crate::Smoothing;
+use crate::time_series::missing_value::interpolation::linear_interpolation;
 
 #[wasm_bindgen]
 impl Smoothing{
     // Holt's Method
     pub fn calculate_holt(&self, alpha:f64, beta:f64) -> Vec<f64> {
+        self.holt_components(alpha, beta).2
+    }
+
+    // Winter's Method
+    pub fn calculate_winter(&self, alpha:f64, beta:f64, gamma:f64, period:usize) -> Vec<f64> {
+        self.winter_components(alpha, beta, gamma, period).3
+    }
+}
+
+impl Smoothing{
+    // Level, trend and one-step forecasts of Holt's method
+    pub fn holt_components(&self, alpha:f64, beta:f64) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
         let mut level: Vec<f64> = Vec::new();
         let mut trend: Vec<f64> = Vec::new();
         let mut holt_values: Vec<f64> = Vec::new();
+        // Missing values (NaN) are interpolated for the start values and replaced
+        // by their one-step forecast afterwards
+        let initial: Vec<f64> = linear_interpolation(&self.get_data());
         for i in 0..self.get_data().len(){
             if i == 0{
                 level.push(0.0);
                 trend.push(0.0);
                 holt_values.push(0.0);
             } else if i == 1{
-                level.push(self.get_data()[1]);
-                trend.push(self.get_data()[1] - self.get_data()[0]);
+                level.push(initial[1]);
+                trend.push(initial[1] - initial[0]);
                 holt_values.push(0.0);
             }
             else{
-                level.push((alpha * self.get_data()[i]) + ((1.0 - alpha) * (level[i-1] - trend[i-1])));
+                let observation = if self.get_data()[i].is_nan() { level[i-1] + trend[i-1] } else { self.get_data()[i] };
+                level.push((alpha * observation) + ((1.0 - alpha) * (level[i-1] - trend[i-1])));
                 trend.push(beta * (level[i] - level[i-1]) + (1.0 - beta) * trend[i-1]);
                 holt_values.push(level[i-1] + trend[i-1]);
             }
         }
-        holt_values
+        (level, trend, holt_values)
     }
 
-    // Winter's Method
-    pub fn calculate_winter(&self, alpha:f64, beta:f64, gamma:f64, period:usize) -> Vec<f64> {
+    // Level, trend, seasonal indices and one-step forecasts of Winter's method
+    pub fn winter_components(&self, alpha:f64, beta:f64, gamma:f64, period:usize) -> (Vec<f64>, Vec<f64>, Vec<f64>, Vec<f64>) {
         let mut level: Vec<f64> = Vec::new();
         let mut trend: Vec<f64> = Vec::new();
         let mut seasonal: Vec<f64> = Vec::new();
-        let level_12_avg: f64 = self.get_data()[0..period].iter().sum::<f64>() / period as f64;
+        // Missing values (NaN) are interpolated for the start values and replaced
+        // by their one-step forecast afterwards
+        let initial: Vec<f64> = linear_interpolation(&self.get_data());
+        let level_12_avg: f64 = initial[0..period].iter().sum::<f64>() / period as f64;
         let mut trend_period: Vec<f64> = Vec::new();
         let mut winter_values: Vec<f64> = Vec::new();
         for i in 0..period{
-            trend_period.push(self.get_data()[i+period] - self.get_data()[i]);
+            trend_period.push(initial[i+period] - initial[i]);
         }
         for i in 0..self.get_data().len(){
             if i < period-1{
                 level.push(0.0);
                 trend.push(0.0);
-                seasonal.push(self.get_data()[i]/level_12_avg);
+                seasonal.push(initial[i]/level_12_avg);
                 winter_values.push(0.0);
             } else if i == period-1{
                 level.push(level_12_avg);
                 trend.push(trend_period.iter().sum::<f64>() / (period).pow(2) as f64);
-                seasonal.push(self.get_data()[i]/level_12_avg);
+                seasonal.push(initial[i]/level_12_avg);
                 winter_values.push(0.0);
             }
             else{
-                level.push(alpha * self.get_data()[i] / seasonal[i - period as usize] + (1.0 - alpha) * (level[i-1] + trend[i-1]));
+                let observation = if self.get_data()[i].is_nan() { (level[i-1] - trend[i-1]) * seasonal[i - period] } else { self.get_data()[i] };
+                level.push(alpha * observation / seasonal[i - period] + (1.0 - alpha) * (level[i-1] + trend[i-1]));
                 trend.push(beta * (level[i] - level[i-1]) + (1.0 - beta) * trend[i-1]);
-                seasonal.push(gamma * self.get_data()[i] / level[i] + (1.0 - gamma) * seasonal[i - period as usize]);
+                seasonal.push(gamma * observation / level[i] + (1.0 - gamma) * seasonal[i - period]);
                 winter_values.push((level[i-1] - trend[i-1]) * seasonal[i - period as usize]);
             }
         }
-        winter_values
+        (level, trend, seasonal, winter_values)
     }
 }
\ No newline at end of file
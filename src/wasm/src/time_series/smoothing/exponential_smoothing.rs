@@ -7,12 +7,18 @@ impl Smoothing {
     pub fn calculate_ses(&self, alpha:f64) -> Vec<f64> {
         let data: Vec<f64> = self.get_data();
         let mut ses_values: Vec<f64> = Vec::new();
+        // Start from the first observed value when the series begins with missing values
+        let initial: f64 = data.iter().cloned().find(|x| !x.is_nan()).unwrap_or(0.0);
         for i in 0..data.len(){
             if i == 0{
                 ses_values.push(0.0);
             }
             else if i == 1{
-                ses_values.push(data[i-1]);
+                ses_values.push(initial);
+            }
+            // A missing value (NaN) leaves the forecast unchanged
+            else if data[i-1].is_nan(){
+                ses_values.push(ses_values[i-1]);
             }
             else{
                 ses_values.push(alpha * data[i-1] + (1.0 - alpha) * ses_values[i-1]);
@@ -0,0 +1,111 @@
+use wasm_bindgen::prelude::*;
+use crate::Smoothing;
+
+// One-step demand-rate forecasts of Croston ("croston"), Syntetos-Boylan ("sba") or
+// Teunter-Syntetos-Babai ("tsb"), and the rate forecast after the last period.
+// alpha smooths the demand sizes; beta smooths the intervals (Croston, SBA) or the
+// probability of a demand (TSB). Periods up to the first demand are forecast as 0 and
+// only initialise the size, the interval and the probability of one demand in that interval.
+pub fn intermittent_demand(data: &[f64], method: &str, alpha: f64, beta: f64) -> (Vec<f64>, f64) {
+    let mut fitted: Vec<f64> = Vec::new();
+    let first = match data.iter().position(|x| *x > 0.0) {
+        Some(first) => first,
+        None => return (vec![0.0; data.len()], 0.0),
+    };
+    let mut size = data[first];
+    let mut interval = (first + 1) as f64;
+    let mut probability = 1.0 / interval;
+    let mut since_demand = 0;
+    let rate = |size: f64, interval: f64, probability: f64| match method {
+        "croston" => size / interval,
+        "sba" => (1.0 - beta / 2.0) * size / interval,
+        "tsb" => probability * size,
+        _ => panic!("Unknown intermittent demand method: {}", method),
+    };
+    for (i, x) in data.iter().enumerate() {
+        fitted.push(if i <= first { 0.0 } else { rate(size, interval, probability) });
+        // Intervals are counted from the first demand
+        if i <= first {
+            continue;
+        }
+        since_demand += 1;
+        // A missing value (NaN) leaves the estimates unchanged
+        if x.is_nan() {
+            continue;
+        }
+        if method == "tsb" {
+            if *x > 0.0 {
+                probability += beta * (1.0 - probability);
+                size += alpha * (x - size);
+            } else {
+                probability -= beta * probability;
+            }
+        } else if *x > 0.0 {
+            size += alpha * (x - size);
+            interval += beta * (since_demand as f64 - interval);
+            since_demand = 0;
+        }
+    }
+    (fitted, rate(size, interval, probability))
+}
+
+// Mean squared one-step error after the first demand
+fn intermittent_mse(data: &[f64], method: &str, alpha: f64, beta: f64) -> f64 {
+    let (fitted, _) = intermittent_demand(data, method, alpha, beta);
+    let first = data.iter().position(|x| *x > 0.0).unwrap_or(data.len());
+    let errors: Vec<f64> = (first + 1..data.len()).filter(|i| !data[*i].is_nan()).map(|i| (data[i] - fitted[i]).powi(2)).collect();
+    if errors.is_empty() {
+        return f64::INFINITY;
+    }
+    errors.iter().sum::<f64>() / errors.len() as f64
+}
+
+#[wasm_bindgen]
+impl Smoothing {
+    // Croston's Method
+    pub fn calculate_croston(&self, alpha: f64, beta: f64) -> Vec<f64> {
+        intermittent_demand(&self.get_data(), "croston", alpha, beta).0
+    }
+
+    // Syntetos-Boylan Approximation
+    pub fn calculate_sba(&self, alpha: f64, beta: f64) -> Vec<f64> {
+        intermittent_demand(&self.get_data(), "sba", alpha, beta).0
+    }
+
+    // Teunter-Syntetos-Babai Method
+    pub fn calculate_tsb(&self, alpha: f64, beta: f64) -> Vec<f64> {
+        intermittent_demand(&self.get_data(), "tsb", alpha, beta).0
+    }
+
+    // Smoothing constants [alpha, beta] minimising the one-step MSE, by a coarse grid refined around its best point
+    pub fn optimize_intermittent(&self, method: String) -> Vec<f64> {
+        let data = self.get_data();
+        let mut best = (0.1, 0.1);
+        let mut best_mse = f64::INFINITY;
+        let mut search = |alphas: Vec<f64>, betas: Vec<f64>, best: &mut (f64, f64)| {
+            for alpha in alphas.iter() {
+                for beta in betas.iter() {
+                    let mse = intermittent_mse(&data, &method, *alpha, *beta);
+                    if mse < best_mse {
+                        best_mse = mse;
+                        *best = (*alpha, *beta);
+                    }
+                }
+            }
+        };
+        let coarse: Vec<f64> = (1..50).map(|k| k as f64 * 0.02).collect();
+        search(coarse.clone(), coarse, &mut best);
+        let fine = |center: f64| -> Vec<f64> {
+            (-10..=10).map(|k| ((center * 1000.0).round() + 2.0 * k as f64) / 1000.0).filter(|x| (0.01..=0.99).contains(x)).collect()
+        };
+        let (alphas, betas) = (fine(best.0), fine(best.1));
+        search(alphas, betas, &mut best);
+        vec![best.0, best.1]
+    }
+
+    // Demand rate per period over the next horizon periods
+    pub fn forecast_intermittent(&self, method: String, alpha: f64, beta: f64, horizon: usize) -> Vec<f64> {
+        let (_, rate) = intermittent_demand(&self.get_data(), &method, alpha, beta);
+        vec![rate; horizon]
+    }
+}
@@ -4,4 +4,5 @@ pub mod exponential_smoothing;
 pub mod advance_exponential_smoothing;
 pub mod smoothing_evaluation;
 
-pub use smoothing::Smoothing;
\ No newline at end of file
+pub use smoothing::Smoothing;
+pub mod intermittent_demand;
\ No newline at end of file
@@ -12,7 +12,9 @@ impl Smoothing {
             if i < distance-1{
                 sma_values.push(0.0);
             }else{
-                avg = data[i-distance+1..i+1].iter().sum::<f64>() / distance as f64;
+                // Average the observed values of the window, skipping missing values (NaN)
+                let window: Vec<f64> = data[i-distance+1..i+1].iter().cloned().filter(|x| !x.is_nan()).collect();
+                avg = window.iter().sum::<f64>() / window.len() as f64;
                 sma_values.push(avg);
             }
         }
@@ -45,7 +47,8 @@ impl Smoothing {
     pub fn calculate_wma(&self, distance: usize) -> Vec<f64> {
         let data: Vec<f64> = self.get_data();
         let mut wma_values: Vec<f64> = Vec::new();
-        let avg: f64 = data[0..distance].iter().sum::<f64>() / distance as f64;
+        let observed: Vec<f64> = data[0..distance].iter().cloned().filter(|x| !x.is_nan()).collect();
+        let avg: f64 = observed.iter().sum::<f64>() / observed.len() as f64;
         let mut dev: Vec<f64> = Vec::new();
         let mut weight: Vec<f64> = Vec::new();
         let mut norm_weight: Vec<f64> = Vec::new();
@@ -53,6 +56,14 @@ impl Smoothing {
             dev.push((data[i] - avg).abs() as f64);
             weight.push((1.0 / dev[i]) as f64);
         }
+        // Positions that are missing (NaN) in the first window take the mean weight
+        let observed_weight: Vec<f64> = weight.iter().cloned().filter(|w| !w.is_nan()).collect();
+        let mean_weight: f64 = observed_weight.iter().sum::<f64>() / observed_weight.len() as f64;
+        for w in weight.iter_mut(){
+            if w.is_nan(){
+                *w = mean_weight;
+            }
+        }
         let sum_weight = weight.iter().sum::<f64>() as f64;
         for i in 0..distance{
             norm_weight.push(weight[i] / sum_weight as f64);
@@ -61,11 +72,16 @@ impl Smoothing {
             if i < distance-1{
                 wma_values.push(0.0);
             }else{
+                // Missing values (NaN) are skipped and the remaining weights rescaled
                 let mut sum_temp = 0.0;
+                let mut sum_weight_temp = 0.0;
                 for j in 0..distance{
-                    sum_temp += data[i-distance+j+1] * norm_weight[j] as f64;
+                    if !data[i-distance+j+1].is_nan(){
+                        sum_temp += data[i-distance+j+1] * norm_weight[j];
+                        sum_weight_temp += norm_weight[j];
+                    }
                 }
-                wma_values.push(sum_temp);
+                wma_values.push(sum_temp / sum_weight_temp);
             }
         }
         wma_values
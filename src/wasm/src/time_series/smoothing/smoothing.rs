@@ -1,4 +1,5 @@
 use wasm_bindgen::prelude::*;
+use crate::TimeIndex;
 
 #[wasm_bindgen]
 pub struct Smoothing {
@@ -33,6 +34,9 @@ impl Smoothing{
     pub fn get_time_header(&self) -> String {
         self.time_header.clone()
     }
+    pub fn get_time_index(&self) -> TimeIndex {
+        TimeIndex::new(self.time.clone(), self.time_header.clone())
+    }
 
     // Setter
     pub fn set_data_header(&mut self, data_header: String) {
@@ -0,0 +1,86 @@
+use nalgebra::{DMatrix, DVector};
+use std::f64::consts::PI;
+use crate::time_series::state_space::state_space::{StateSpaceModel, DIFFUSE_VARIANCE};
+
+// Output of the Kalman filter; a and p are the one-step predictions of the state
+pub struct KalmanFilterResult {
+    pub a: Vec<DVector<f64>>,
+    pub p: Vec<DMatrix<f64>>,
+    pub v: Vec<f64>,
+    pub f: Vec<f64>,
+    pub k: Vec<DVector<f64>>,
+    pub missing: Vec<bool>,
+    pub diffuse: Vec<bool>,
+    pub log_likelihood: f64,
+}
+
+impl KalmanFilterResult {
+    // Prediction variance-weighted sum of squared innovations and number of non-diffuse observations
+    pub fn sum_square_innovation(&self) -> (f64, usize) {
+        let mut sum = 0.0;
+        let mut count = 0;
+        for i in 0..self.v.len() {
+            if !self.missing[i] && !self.diffuse[i] {
+                sum += self.v[i].powi(2) / self.f[i];
+                count += 1;
+            }
+        }
+        (sum, count)
+    }
+
+    // Sum of the log prediction variances of the non-diffuse observations
+    pub fn sum_log_variance(&self) -> f64 {
+        (0..self.f.len()).filter(|i| !self.missing[*i] && !self.diffuse[*i]).map(|i| self.f[i].ln()).sum()
+    }
+}
+
+impl StateSpaceModel {
+    // Kalman filter treating NaN observations as missing
+    pub fn kalman_filter(&self, y: &[f64]) -> KalmanFilterResult {
+        let z = self.get_z();
+        let t = self.get_t();
+        let rqr = self.get_r() * self.get_q() * self.get_r().transpose();
+        let mut a: DVector<f64> = self.get_a0().clone();
+        let mut p: DMatrix<f64> = self.get_p0().clone();
+
+        let mut result = KalmanFilterResult {
+            a: Vec::new(),
+            p: Vec::new(),
+            v: Vec::new(),
+            f: Vec::new(),
+            k: Vec::new(),
+            missing: Vec::new(),
+            diffuse: Vec::new(),
+            log_likelihood: 0.0,
+        };
+        for value in y.iter() {
+            let v = value - self.get_d() - z.dot(&a);
+            let pz: DVector<f64> = &p * z;
+            let f = z.dot(&pz) + self.get_h();
+            let missing = value.is_nan() || f <= 1e-12;
+            let diffuse = f > DIFFUSE_VARIANCE / 100.0;
+
+            result.a.push(a.clone());
+            result.p.push(p.clone());
+            let k: DVector<f64> = if missing { DVector::zeros(z.len()) } else { t * &pz / f };
+            if missing {
+                a = t * &a;
+                p = t * &p * t.transpose() + &rqr;
+            } else {
+                if !diffuse {
+                    result.log_likelihood -= 0.5 * ((2.0 * PI).ln() + f.ln() + v * v / f);
+                }
+                let l: DMatrix<f64> = t - &k * z.transpose();
+                a = t * &a + &k * v;
+                p = t * &p * l.transpose() + &rqr;
+            }
+            p = (&p + p.transpose()) * 0.5;
+            result.v.push(if missing { 0.0 } else { v });
+            result.f.push(f);
+            result.k.push(k);
+            result.missing.push(missing);
+            result.diffuse.push(diffuse);
+        }
+        result
+    }
+}
@@ -0,0 +1,41 @@
+use nalgebra::{DMatrix, DVector};
+use crate::time_series::state_space::state_space::StateSpaceModel;
+use crate::time_series::state_space::kalman_filter::KalmanFilterResult;
+
+// Smoothed states and their variances
+pub struct KalmanSmootherResult {
+    pub alpha: Vec<DVector<f64>>,
+    pub v: Vec<DMatrix<f64>>,
+}
+
+impl StateSpaceModel {
+    // Fixed-interval state smoother (Durbin and Koopman, 2012, section 4.4)
+    pub fn kalman_smoother(&self, filter: &KalmanFilterResult) -> KalmanSmootherResult {
+        let z = self.get_z();
+        let t = self.get_t();
+        let m = z.len();
+        let n = filter.v.len();
+        let mut r: DVector<f64> = DVector::zeros(m);
+        let mut nn: DMatrix<f64> = DMatrix::zeros(m, m);
+        let mut alpha: Vec<DVector<f64>> = vec![DVector::zeros(m); n];
+        let mut variance: Vec<DMatrix<f64>> = vec![DMatrix::zeros(m, m); n];
+        for i in (0..n).rev() {
+            if filter.missing[i] {
+                r = t.transpose() * &r;
+                nn = t.transpose() * &nn * t;
+            } else {
+                let l: DMatrix<f64> = t - &filter.k[i] * z.transpose();
+                r = z * (filter.v[i] / filter.f[i]) + l.transpose() * &r;
+                nn = z * z.transpose() / filter.f[i] + l.transpose() * &nn * &l;
+            }
+            alpha[i] = &filter.a[i] + &filter.p[i] * &r;
+            variance[i] = &filter.p[i] - &filter.p[i] * &nn * &filter.p[i];
+        }
+        KalmanSmootherResult { alpha, v: variance }
+    }
+
+    // Smoothed signal d + z' alpha_t
+    pub fn smoothed_signal(&self, smoother: &KalmanSmootherResult) -> Vec<f64> {
+        smoother.alpha.iter().map(|a| self.get_d() + self.get_z().dot(a)).collect()
+    }
+}
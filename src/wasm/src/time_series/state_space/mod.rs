@@ -0,0 +1,5 @@
+pub mod state_space;
+pub mod kalman_filter;
+pub mod kalman_smoother;
+
+pub use state_space::StateSpaceModel;
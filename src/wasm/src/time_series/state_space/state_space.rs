@@ -0,0 +1,115 @@
+use nalgebra::{DMatrix, DVector};
+
+// Variance of the diffuse initial state
+pub const DIFFUSE_VARIANCE: f64 = 1e7;
+
+// Univariate linear Gaussian state space model
+//   y_t = d + z' alpha_t + eps_t,            eps_t ~ N(0, h)
+//   alpha_{t+1} = t alpha_t + r eta_t,       eta_t ~ N(0, q)
+pub struct StateSpaceModel {
+    z: DVector<f64>,
+    t: DMatrix<f64>,
+    r: DMatrix<f64>,
+    q: DMatrix<f64>,
+    h: f64,
+    d: f64,
+    a0: DVector<f64>,
+    p0: DMatrix<f64>,
+}
+
+impl StateSpaceModel {
+    pub fn new(z: DVector<f64>, t: DMatrix<f64>, r: DMatrix<f64>, q: DMatrix<f64>, h: f64, d: f64) -> StateSpaceModel {
+        let m = z.len();
+        StateSpaceModel {
+            z,
+            t,
+            r,
+            q,
+            h,
+            d,
+            a0: DVector::zeros(m),
+            p0: DMatrix::identity(m, m) * DIFFUSE_VARIANCE,
+        }
+    }
+
+    // Random walk plus noise, h is the noise variance and q the level variance
+    pub fn local_level(h: f64, q: f64) -> StateSpaceModel {
+        StateSpaceModel::new(
+            DVector::from_element(1, 1.0),
+            DMatrix::from_element(1, 1, 1.0),
+            DMatrix::from_element(1, 1, 1.0),
+            DMatrix::from_element(1, 1, q),
+            h,
+            0.0,
+        )
+    }
+
+    // ARIMA(p, d, q) in Harvey's form, with phi(B)(1 - B)^d expanded into one AR polynomial
+    // and theta(B) = 1 + theta_1 B + ... as used by the arima crate
+    pub fn arima(ar_coef: &[f64], ma_coef: &[f64], i_order: usize, mean: f64) -> StateSpaceModel {
+        let mut phi: Vec<f64> = vec![1.0];
+        phi.extend(ar_coef.iter().map(|c| -c));
+        for _ in 0..i_order {
+            let mut next: Vec<f64> = vec![0.0; phi.len() + 1];
+            for (i, c) in phi.iter().enumerate() {
+                next[i] += c;
+                next[i + 1] -= c;
+            }
+            phi = next;
+        }
+        let ar: Vec<f64> = phi[1..].iter().map(|c| -c).collect();
+        let m = ar.len().max(ma_coef.len() + 1);
+
+        let mut t = DMatrix::zeros(m, m);
+        for i in 0..m {
+            if i < ar.len() {
+                t[(i, 0)] = ar[i];
+            }
+            if i + 1 < m {
+                t[(i, i + 1)] = 1.0;
+            }
+        }
+        let mut r = DMatrix::zeros(m, 1);
+        r[(0, 0)] = 1.0;
+        for (i, theta) in ma_coef.iter().enumerate() {
+            r[(i + 1, 0)] = *theta;
+        }
+        let mut z = DVector::zeros(m);
+        z[0] = 1.0;
+        StateSpaceModel::new(z, t, r, DMatrix::from_element(1, 1, 1.0), 0.0, mean)
+    }
+
+    // Getters
+    pub fn get_z(&self) -> &DVector<f64> {
+        &self.z
+    }
+    pub fn get_t(&self) -> &DMatrix<f64> {
+        &self.t
+    }
+    pub fn get_r(&self) -> &DMatrix<f64> {
+        &self.r
+    }
+    pub fn get_q(&self) -> &DMatrix<f64> {
+        &self.q
+    }
+    pub fn get_h(&self) -> f64 {
+        self.h
+    }
+    pub fn get_d(&self) -> f64 {
+        self.d
+    }
+    pub fn get_a0(&self) -> &DVector<f64> {
+        &self.a0
+    }
+    pub fn get_p0(&self) -> &DMatrix<f64> {
+        &self.p0
+    }
+
+    // Setters
+    pub fn set_a0(&mut self, a0: DVector<f64>) {
+        self.a0 = a0;
+    }
+    pub fn set_p0(&mut self, p0: DMatrix<f64>) {
+        self.p0 = p0;
+    }
+}
@@ -0,0 +1,107 @@
+use wasm_bindgen::prelude::*;
+use nalgebra::DVector;
+use crate::{StructuralModel, TimeIndex};
+use crate::time_series::benchmark::prediction_interval::interval_multiplier;
+use crate::time_series::structural_model::structural_system::StructuralSpec;
+use crate::time_series::structural_model::estimation::estimate_structural;
+
+const COMPONENT_NAME: [&str; 4] = ["level", "slope", "seasonal", "cycle"];
+
+#[wasm_bindgen]
+impl StructuralModel {
+    // Fit a structural model by maximum likelihood: trend is "level" (local level) or "trend"
+    // (local linear trend), seasonal is "none", "dummy" or "trigonometric" with the period, and
+    // cycle adds a damped stochastic cycle. Missing values (NaN) are skipped by the Kalman
+    // filter. Returns the forecasts of the next horizon periods
+    pub fn calculate_structural_model(&mut self, trend: String, seasonal: String, period: usize, cycle: bool, horizon: usize, level: f64) -> Vec<f64> {
+        let spec = StructuralSpec::new(&trend, &seasonal, period, cycle);
+        let data = self.get_data();
+        let n = data.len();
+        let observed: Vec<f64> = data.iter().cloned().filter(|x| x.is_finite()).collect();
+        if observed.len() < spec.state_size() + 3 {
+            panic!("Structural model needs more observations than states");
+        }
+
+        // Estimate on unit scale so the diffuse start is recognized whatever the data scale
+        let mean = observed.iter().sum::<f64>() / observed.len() as f64;
+        let sd = (observed.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / observed.len() as f64).sqrt();
+        let scale = if sd > 0.0 { sd } else { 1.0 };
+        let scaled: Vec<f64> = data.iter().map(|x| x / scale).collect();
+        let parameter = estimate_structural(&spec, &scaled);
+        let model = spec.model(&parameter);
+
+        // Filtering past the sample gives the forecasts and their variances
+        let mut extended = scaled.clone();
+        extended.extend(vec![f64::NAN; horizon]);
+        let filter = model.kalman_filter(&extended);
+        let z = model.get_z();
+        let mut fitted = Vec::new();
+        let mut filtered: Vec<Vec<f64>> = vec![Vec::new(); 4];
+        let mut diffuse_count = 0;
+        for t in 0..n {
+            let state: DVector<f64> = if filter.missing[t] { filter.a[t].clone() } else { &filter.a[t] + &filter.p[t] * z * (filter.v[t] / filter.f[t]) };
+            for (i, value) in spec.component(&state).iter().enumerate() {
+                filtered[i].push(value * scale);
+            }
+            if filter.diffuse[t] && !filter.missing[t] {
+                diffuse_count += 1;
+            }
+            fitted.push(if filter.diffuse[t] { f64::NAN } else { z.dot(&filter.a[t]) * scale });
+        }
+        let smoother = model.kalman_smoother(&model.kalman_filter(&scaled));
+        let smoothed: Vec<Vec<f64>> = (0..4).map(|i| smoother.alpha.iter().map(|a| spec.component(a)[i] * scale).collect()).collect();
+        let forecast: Vec<f64> = (n..n + horizon).map(|t| z.dot(&filter.a[t]) * scale).collect();
+        let forecast_se: Vec<f64> = (n..n + horizon).map(|t| filter.f[t].sqrt() * scale).collect();
+
+        // Back to the data scale: variances by scale^2 and the likelihood by the Jacobian
+        let variance_count = spec.variance_name().len();
+        let parameter: Vec<f64> = parameter.iter().enumerate().map(|(i, p)| if i < variance_count { p * scale * scale } else { *p }).collect();
+        let log_likelihood = filter.log_likelihood - (observed.len() - diffuse_count) as f64 * scale.ln();
+        let mut parameter_name = spec.variance_name();
+        if cycle {
+            parameter_name.push("cycle_damping".to_string());
+            parameter_name.push("cycle_period".to_string());
+        }
+
+        let multiplier = interval_multiplier(level);
+        self.set_lower_bound(forecast.iter().zip(forecast_se.iter()).map(|(f, se)| f - multiplier * se).collect());
+        self.set_upper_bound(forecast.iter().zip(forecast_se.iter()).map(|(f, se)| f + multiplier * se).collect());
+        let time_index = TimeIndex::new(self.get_time(), self.get_time_header());
+        let forecast_time = if time_index.get_frequency() == "unknown" { Vec::new() } else { time_index.generate_future_label(horizon) };
+        self.set_forecast_time(forecast_time);
+        self.set_specification(trend, seasonal, period, cycle, parameter_name);
+        self.set_parameter(parameter);
+        self.set_log_likelihood(log_likelihood);
+        self.set_fitted(fitted);
+        self.set_filtered(filtered);
+        self.set_smoothed(smoothed);
+        self.set_forecast_se(forecast_se);
+        self.set_forecast(forecast.clone());
+        forecast
+    }
+
+    // Filtered "level", "slope", "seasonal" or "cycle" component
+    pub fn get_filtered_component(&self, component: String) -> Vec<f64> {
+        match COMPONENT_NAME.iter().position(|c| *c == component) {
+            Some(i) => self.get_filtered().get(i).cloned().unwrap_or_default(),
+            None => panic!("Unknown component: {}", component),
+        }
+    }
+
+    // Smoothed "level", "slope", "seasonal" or "cycle" component
+    pub fn get_smoothed_component(&self, component: String) -> Vec<f64> {
+        match COMPONENT_NAME.iter().position(|c| *c == component) {
+            Some(i) => self.get_smoothed().get(i).cloned().unwrap_or_default(),
+            None => panic!("Unknown component: {}", component),
+        }
+    }
+
+    pub fn aic(&self) -> f64 {
+        -2.0 * self.get_log_likelihood() + 2.0 * self.get_parameter().len() as f64
+    }
+
+    pub fn bic(&self) -> f64 {
+        let n = self.get_data().iter().filter(|x| x.is_finite()).count() as f64;
+        -2.0 * self.get_log_likelihood() + self.get_parameter().len() as f64 * n.ln()
+    }
+}
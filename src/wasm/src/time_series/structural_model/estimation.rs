@@ -0,0 +1,47 @@
+use crate::time_series::structural_model::structural_system::StructuralSpec;
+use crate::time_series::structural_model::nelder_mead::nelder_mead;
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+// Variances from their logs; the cycle damping lies in (0, 1) and its period in (2, n)
+pub fn natural_parameter(spec: &StructuralSpec, theta: &[f64], n: usize) -> Vec<f64> {
+    let variance_count = spec.variance_name().len();
+    let mut parameter: Vec<f64> = theta[..variance_count].iter().map(|x| x.exp()).collect();
+    if spec.cycle {
+        parameter.push(logistic(theta[variance_count]));
+        parameter.push(2.0 + (n as f64 - 2.0).max(1.0) * logistic(theta[variance_count + 1]));
+    }
+    parameter
+}
+
+// Log-likelihood of the parameters, excluding the diffuse start
+pub fn structural_log_likelihood(spec: &StructuralSpec, data: &[f64], parameter: &[f64]) -> f64 {
+    spec.model(parameter).kalman_filter(data).log_likelihood
+}
+
+// Maximum likelihood parameters by Nelder-Mead on the log variances, restarted once from the optimum.
+// The data should be of unit scale for the diffuse start to be recognized
+pub fn estimate_structural(spec: &StructuralSpec, data: &[f64]) -> Vec<f64> {
+    let variance_count = spec.variance_name().len();
+    let mut theta: Vec<f64> = vec![(0.1_f64).ln(); variance_count];
+    if spec.cycle {
+        // Damping 0.9 and a period of a fifth of the sample, at least twice the seasonal period
+        let target = (data.len() as f64 / 5.0).max(2.0 * spec.period as f64).min(data.len() as f64 - 1.0);
+        let share = ((target - 2.0) / (data.len() as f64 - 2.0).max(1.0)).clamp(0.01, 0.99);
+        theta.push((0.9_f64 / 0.1).ln());
+        theta.push((share / (1.0 - share)).ln());
+    }
+    let objective = |x: &[f64]| {
+        if x[..variance_count].iter().any(|v| *v < -30.0 || *v > 10.0) {
+            return f64::INFINITY;
+        }
+        let ll = structural_log_likelihood(spec, data, &natural_parameter(spec, x, data.len()));
+        if ll.is_finite() { -ll } else { f64::INFINITY }
+    };
+    for _ in 0..2 {
+        theta = nelder_mead(objective, &theta, 1.0, 400 * theta.len());
+    }
+    natural_parameter(spec, &theta, data.len())
+}
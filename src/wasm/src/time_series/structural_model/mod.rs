@@ -0,0 +1,7 @@
+pub mod structural_model;
+pub mod structural_system;
+pub mod nelder_mead;
+pub mod estimation;
+pub mod calculate_structural_model;
+
+pub use structural_model::StructuralModel;
\ No newline at end of file
@@ -0,0 +1,56 @@
+// Nelder-Mead simplex minimization of f from the start point with the given initial step
+pub fn nelder_mead<F: Fn(&[f64]) -> f64>(f: F, start: &[f64], step: f64, max_iter: usize) -> Vec<f64> {
+    let k = start.len();
+    let mut simplex: Vec<Vec<f64>> = vec![start.to_vec()];
+    for i in 0..k {
+        let mut point = start.to_vec();
+        point[i] += step;
+        simplex.push(point);
+    }
+    let mut value: Vec<f64> = simplex.iter().map(|p| f(p)).collect();
+    let toward = |a: &[f64], b: &[f64], c: f64| -> Vec<f64> { a.iter().zip(b.iter()).map(|(x, y)| x + c * (y - x)).collect() };
+
+    for _ in 0..max_iter {
+        let mut order: Vec<usize> = (0..=k).collect();
+        order.sort_by(|a, b| value[*a].partial_cmp(&value[*b]).unwrap_or(std::cmp::Ordering::Equal));
+        simplex = order.iter().map(|i| simplex[*i].clone()).collect();
+        value = order.iter().map(|i| value[*i]).collect();
+        if (value[k] - value[0]).abs() <= 1e-10 * (1.0 + value[0].abs()) {
+            break;
+        }
+
+        // Reflect the worst point through the centroid of the others
+        let centroid: Vec<f64> = (0..k).map(|j| simplex[..k].iter().map(|p| p[j]).sum::<f64>() / k as f64).collect();
+        let reflected = toward(&centroid, &simplex[k], -1.0);
+        let reflected_value = f(&reflected);
+        if reflected_value < value[0] {
+            let expanded = toward(&centroid, &simplex[k], -2.0);
+            let expanded_value = f(&expanded);
+            if expanded_value < reflected_value {
+                simplex[k] = expanded;
+                value[k] = expanded_value;
+            } else {
+                simplex[k] = reflected;
+                value[k] = reflected_value;
+            }
+        } else if reflected_value < value[k - 1] {
+            simplex[k] = reflected;
+            value[k] = reflected_value;
+        } else {
+            let contracted = toward(&centroid, &simplex[k], 0.5);
+            let contracted_value = f(&contracted);
+            if contracted_value < value[k] {
+                simplex[k] = contracted;
+                value[k] = contracted_value;
+            } else {
+                // Shrink towards the best point
+                for i in 1..=k {
+                    simplex[i] = toward(&simplex[0], &simplex[i], 0.5);
+                    value[i] = f(&simplex[i]);
+                }
+            }
+        }
+    }
+    let best = (0..=k).min_by(|a, b| value[*a].partial_cmp(&value[*b]).unwrap_or(std::cmp::Ordering::Equal)).unwrap();
+    simplex[best].clone()
+}
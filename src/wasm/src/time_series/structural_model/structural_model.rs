@@ -0,0 +1,157 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct StructuralModel {
+    data: Vec<f64>,
+    data_header: String,
+    time: Vec<String>,
+    time_header: String,
+    trend: String,                // "level" or "trend"
+    seasonal: String,             // "none", "dummy" or "trigonometric"
+    period: usize,
+    cycle: bool,
+    parameter_name: Vec<String>,  // Disturbance variances, then the cycle damping and period
+    parameter: Vec<f64>,
+    log_likelihood: f64,
+    fitted: Vec<f64>,             // One-step predictions, NaN over the diffuse start
+    filtered: Vec<Vec<f64>>,      // Level, slope, seasonal and cycle given the data up to t
+    smoothed: Vec<Vec<f64>>,      // Level, slope, seasonal and cycle given all the data
+    forecast: Vec<f64>,
+    forecast_se: Vec<f64>,
+    lower_bound: Vec<f64>,
+    upper_bound: Vec<f64>,
+    forecast_time: Vec<String>,   // Labels of the forecast periods, empty when the time labels are not parsed
+}
+
+#[wasm_bindgen]
+impl StructuralModel {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, time: Vec<String>, time_header: String) -> StructuralModel {
+        StructuralModel {
+            data,
+            data_header,
+            time,
+            time_header,
+            trend: "level".to_string(),
+            seasonal: "none".to_string(),
+            period: 1,
+            cycle: false,
+            parameter_name: Vec::new(),
+            parameter: Vec::new(),
+            log_likelihood: f64::NAN,
+            fitted: Vec::new(),
+            filtered: Vec::new(),
+            smoothed: Vec::new(),
+            forecast: Vec::new(),
+            forecast_se: Vec::new(),
+            lower_bound: Vec::new(),
+            upper_bound: Vec::new(),
+            forecast_time: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String {
+        self.data_header.clone()
+    }
+    pub fn get_time(&self) -> Vec<String> {
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String {
+        self.time_header.clone()
+    }
+    pub fn get_trend(&self) -> String {
+        self.trend.clone()
+    }
+    pub fn get_seasonal(&self) -> String {
+        self.seasonal.clone()
+    }
+    pub fn get_period(&self) -> usize {
+        self.period
+    }
+    pub fn get_cycle(&self) -> bool {
+        self.cycle
+    }
+    pub fn get_parameter_name(&self) -> Vec<String> {
+        self.parameter_name.clone()
+    }
+    pub fn get_parameter(&self) -> Vec<f64> {
+        self.parameter.clone()
+    }
+    pub fn get_log_likelihood(&self) -> f64 {
+        self.log_likelihood
+    }
+    pub fn get_fitted(&self) -> Vec<f64> {
+        self.fitted.clone()
+    }
+    pub fn get_forecast(&self) -> Vec<f64> {
+        self.forecast.clone()
+    }
+    pub fn get_forecast_se(&self) -> Vec<f64> {
+        self.forecast_se.clone()
+    }
+    pub fn get_lower_bound(&self) -> Vec<f64> {
+        self.lower_bound.clone()
+    }
+    pub fn get_upper_bound(&self) -> Vec<f64> {
+        self.upper_bound.clone()
+    }
+    pub fn get_forecast_time(&self) -> Vec<String> {
+        self.forecast_time.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_parameter(&mut self, parameter: Vec<f64>) {
+        self.parameter = parameter;
+    }
+    pub fn set_log_likelihood(&mut self, log_likelihood: f64) {
+        self.log_likelihood = log_likelihood;
+    }
+    pub fn set_fitted(&mut self, fitted: Vec<f64>) {
+        self.fitted = fitted;
+    }
+    pub fn set_forecast(&mut self, forecast: Vec<f64>) {
+        self.forecast = forecast;
+    }
+    pub fn set_forecast_se(&mut self, forecast_se: Vec<f64>) {
+        self.forecast_se = forecast_se;
+    }
+    pub fn set_lower_bound(&mut self, lower_bound: Vec<f64>) {
+        self.lower_bound = lower_bound;
+    }
+    pub fn set_upper_bound(&mut self, upper_bound: Vec<f64>) {
+        self.upper_bound = upper_bound;
+    }
+    pub fn set_forecast_time(&mut self, forecast_time: Vec<String>) {
+        self.forecast_time = forecast_time;
+    }
+}
+
+impl StructuralModel {
+    pub fn get_filtered(&self) -> Vec<Vec<f64>> {
+        self.filtered.clone()
+    }
+    pub fn get_smoothed(&self) -> Vec<Vec<f64>> {
+        self.smoothed.clone()
+    }
+
+    pub fn set_specification(&mut self, trend: String, seasonal: String, period: usize, cycle: bool, parameter_name: Vec<String>) {
+        self.trend = trend;
+        self.seasonal = seasonal;
+        self.period = period;
+        self.cycle = cycle;
+        self.parameter_name = parameter_name;
+    }
+    pub fn set_filtered(&mut self, filtered: Vec<Vec<f64>>) {
+        self.filtered = filtered;
+    }
+    pub fn set_smoothed(&mut self, smoothed: Vec<Vec<f64>>) {
+        self.smoothed = smoothed;
+    }
+}
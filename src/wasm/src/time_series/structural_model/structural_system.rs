@@ -0,0 +1,174 @@
+use nalgebra::{DMatrix, DVector};
+use std::f64::consts::PI;
+use crate::time_series::state_space::StateSpaceModel;
+
+// Components of a basic structural model: a local level or local linear trend ("level" or
+// "trend"), a stochastic seasonal ("none", "dummy" or "trigonometric") and an optional
+// stochastic cycle. The parameters are the disturbance variances in the order of
+// variance_name, followed by the cycle damping and period when there is a cycle
+pub struct StructuralSpec {
+    pub trend: String,
+    pub seasonal: String,
+    pub period: usize,
+    pub cycle: bool,
+}
+
+impl StructuralSpec {
+    pub fn new(trend: &str, seasonal: &str, period: usize, cycle: bool) -> StructuralSpec {
+        if trend != "level" && trend != "trend" {
+            panic!("Unknown trend: {}", trend);
+        }
+        match seasonal {
+            "none" => {}
+            "dummy" | "trigonometric" if period >= 2 => {}
+            "dummy" | "trigonometric" => panic!("Seasonal component needs a period of at least 2"),
+            _ => panic!("Unknown seasonal component: {}", seasonal),
+        }
+        StructuralSpec { trend: trend.to_string(), seasonal: seasonal.to_string(), period, cycle }
+    }
+
+    fn slope_size(&self) -> usize {
+        if self.trend == "trend" { 1 } else { 0 }
+    }
+    fn seasonal_size(&self) -> usize {
+        if self.seasonal == "none" { 0 } else { self.period - 1 }
+    }
+    fn cycle_size(&self) -> usize {
+        if self.cycle { 2 } else { 0 }
+    }
+    pub fn state_size(&self) -> usize {
+        1 + self.slope_size() + self.seasonal_size() + self.cycle_size()
+    }
+
+    pub fn variance_name(&self) -> Vec<String> {
+        let mut name = vec!["irregular".to_string(), "level".to_string()];
+        if self.slope_size() > 0 {
+            name.push("slope".to_string());
+        }
+        if self.seasonal_size() > 0 {
+            name.push("seasonal".to_string());
+        }
+        if self.cycle {
+            name.push("cycle".to_string());
+        }
+        name
+    }
+
+    // State space form of the model, with diffuse starting values except for the stationary cycle
+    pub fn model(&self, parameter: &[f64]) -> StateSpaceModel {
+        let m = self.state_size();
+        let variance_count = self.variance_name().len();
+        let (s, c) = (1 + self.slope_size(), 1 + self.slope_size() + self.seasonal_size());
+        let mut z = DVector::zeros(m);
+        let mut t = DMatrix::zeros(m, m);
+        let mut q_diagonal: Vec<f64> = Vec::new();
+
+        // Level and slope
+        z[0] = 1.0;
+        t[(0, 0)] = 1.0;
+        q_diagonal.push(parameter[1]);
+        if self.slope_size() > 0 {
+            t[(0, 1)] = 1.0;
+            t[(1, 1)] = 1.0;
+            q_diagonal.push(parameter[2]);
+        }
+        let mut next = 2 + self.slope_size();
+
+        // Seasonal: dummy seasonal sums to zero over a period, the trigonometric one rotates
+        // each harmonic by its frequency
+        match self.seasonal.as_str() {
+            "dummy" => {
+                z[s] = 1.0;
+                for j in 0..self.seasonal_size() {
+                    t[(s, s + j)] = -1.0;
+                    if j > 0 {
+                        t[(s + j, s + j - 1)] = 1.0;
+                    }
+                }
+            }
+            "trigonometric" => {
+                let mut i = s;
+                for j in 1..=self.period / 2 {
+                    let lambda = 2.0 * PI * j as f64 / self.period as f64;
+                    z[i] = 1.0;
+                    if 2 * j == self.period {
+                        t[(i, i)] = -1.0;
+                        i += 1;
+                    } else {
+                        t[(i, i)] = lambda.cos();
+                        t[(i, i + 1)] = lambda.sin();
+                        t[(i + 1, i)] = -lambda.sin();
+                        t[(i + 1, i + 1)] = lambda.cos();
+                        i += 2;
+                    }
+                }
+            }
+            _ => {}
+        }
+        if self.seasonal_size() > 0 {
+            let size = if self.seasonal == "dummy" { 1 } else { self.seasonal_size() };
+            q_diagonal.extend(vec![parameter[next]; size]);
+            next += 1;
+        }
+
+        // Damped stochastic cycle
+        let mut cycle_variance = 0.0;
+        let mut damping = 0.0;
+        if self.cycle {
+            damping = parameter[variance_count];
+            let lambda = 2.0 * PI / parameter[variance_count + 1];
+            z[c] = 1.0;
+            t[(c, c)] = damping * lambda.cos();
+            t[(c, c + 1)] = damping * lambda.sin();
+            t[(c + 1, c)] = -damping * lambda.sin();
+            t[(c + 1, c + 1)] = damping * lambda.cos();
+            cycle_variance = parameter[next];
+            q_diagonal.extend(vec![cycle_variance; 2]);
+        }
+
+        // Disturbances enter the level, slope, first (dummy) or all (trigonometric) seasonal states and the cycle
+        let mut rows: Vec<usize> = (0..s).collect();
+        if self.seasonal == "dummy" {
+            rows.push(s);
+        } else {
+            rows.extend(s..c);
+        }
+        rows.extend(c..m);
+        let mut r = DMatrix::zeros(m, rows.len());
+        for (j, i) in rows.iter().enumerate() {
+            r[(*i, j)] = 1.0;
+        }
+        let q = DMatrix::from_diagonal(&DVector::from_vec(q_diagonal));
+        let mut model = StateSpaceModel::new(z, t, r, q, parameter[0], 0.0);
+        if self.cycle {
+            let mut p0 = model.get_p0().clone();
+            for i in c..m {
+                p0[(i, i)] = cycle_variance / (1.0 - damping * damping);
+            }
+            model.set_p0(p0);
+        }
+        model
+    }
+
+    // Level, slope, seasonal and cycle values of a state vector
+    pub fn component(&self, state: &DVector<f64>) -> [f64; 4] {
+        let (s, c) = (1 + self.slope_size(), 1 + self.slope_size() + self.seasonal_size());
+        let slope = if self.slope_size() > 0 { state[1] } else { 0.0 };
+        let seasonal = match self.seasonal.as_str() {
+            "dummy" => state[s],
+            "trigonometric" => {
+                // Only the first state of each harmonic pair enters the observation
+                let mut sum = 0.0;
+                let mut i = s;
+                for j in 1..=self.period / 2 {
+                    sum += state[i];
+                    i += if 2 * j == self.period { 1 } else { 2 };
+                }
+                sum
+            }
+            _ => 0.0,
+        };
+        let cycle = if self.cycle { state[c] } else { 0.0 };
+        [state[0], slope, seasonal, cycle]
+    }
+}
@@ -0,0 +1,150 @@
+use wasm_bindgen::prelude::*;
+use crate::TimeIndex;
+use crate::time_series::time_index::calendar::*;
+use crate::time_series::time_index::parse_label::*;
+
+// Days of the holidays falling in [start, end); "mm-dd" holidays recur every year
+fn holiday_days(holidays: &[ParsedLabel], start: i64, end: i64) -> Vec<i64> {
+    let (first_year, _, _) = civil_from_days(start);
+    let (last_year, _, _) = civil_from_days(end - 1);
+    let mut days: Vec<i64> = Vec::new();
+    for holiday in holidays {
+        match holiday {
+            ParsedLabel::Date(d) => days.push(*d),
+            ParsedLabel::DateTime(m) => days.push(m.div_euclid(1440)),
+            ParsedLabel::MonthDay(m, d) => {
+                for y in first_year..=last_year {
+                    if *d <= days_in_month(y, *m) {
+                        days.push(days_from_civil(y, *m, *d));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    days.retain(|d| *d >= start && *d < end);
+    days.sort();
+    days.dedup();
+    days
+}
+
+fn parse_holidays(holidays: &[String]) -> Vec<ParsedLabel> {
+    let day_first = day_first(holidays);
+    holidays
+        .iter()
+        .map(|h| match parse_label(h, day_first) {
+            Some(p @ (ParsedLabel::Date(_) | ParsedLabel::DateTime(_) | ParsedLabel::MonthDay(_, _))) => p,
+            _ => panic!("Unknown holiday date: {}", h),
+        })
+        .collect()
+}
+
+impl TimeIndex {
+    // First day and the day after the last day covered by an ordinal
+    pub fn day_span(&self, ordinal: i64) -> (i64, i64) {
+        let step = self.get_step() as i64;
+        let month_start = |month: i64| days_from_civil(month.div_euclid(12), month.rem_euclid(12) + 1, 1);
+        match self.get_unit().as_str() {
+            "year" => (days_from_civil(ordinal, 1, 1), days_from_civil(ordinal + step, 1, 1)),
+            "quarter" => (month_start(ordinal * 3), month_start((ordinal + step) * 3)),
+            "month" => (month_start(ordinal), month_start(ordinal + step)),
+            "day" => (ordinal, ordinal + step),
+            "business_day" => (days_from_business(ordinal), days_from_business(ordinal) + 1),
+            "minute" if self.get_label_format() == "datetime" => {
+                let start = ordinal.div_euclid(1440);
+                (start, (ordinal + step).div_euclid(1440).max(start + 1))
+            }
+            _ => panic!("Calendar regressors need calendar time labels"),
+        }
+    }
+
+    // Number of Mondays, Tuesdays, ..., Sundays in each period
+    pub fn weekday_count(&self, horizon: usize) -> Vec<[f64; 7]> {
+        self.extended_ordinal(horizon)
+            .iter()
+            .map(|o| {
+                let (start, end) = self.day_span(*o);
+                let mut count = [0.0; 7];
+                for d in start..end {
+                    count[weekday(d) as usize] += 1.0;
+                }
+                count
+            })
+            .collect()
+    }
+
+    // "td1": weekdays minus 5/2 weekend days, "td6": each of Monday to Saturday minus Sunday
+    pub fn trading_day_regressor(&self, model: &str, horizon: usize) -> Vec<Vec<f64>> {
+        let count = self.weekday_count(horizon);
+        match model {
+            "td1" => vec![count.iter().map(|c| c[..5].iter().sum::<f64>() - 2.5 * (c[5] + c[6])).collect()],
+            "td6" => (0..6).map(|j| count.iter().map(|c| c[j] - c[6]).collect()).collect(),
+            _ => panic!("Unknown trading day model: {}", model),
+        }
+    }
+
+    // Share of the window days before Easter Sunday falling in each period
+    pub fn easter_regressor(&self, window: usize, horizon: usize) -> Vec<f64> {
+        if window == 0 {
+            panic!("Easter window must be at least one day");
+        }
+        self.extended_ordinal(horizon)
+            .iter()
+            .map(|o| {
+                let (start, end) = self.day_span(*o);
+                let (first_year, _, _) = civil_from_days(start);
+                let (last_year, _, _) = civil_from_days(end - 1);
+                let mut days = 0;
+                for y in first_year..=last_year {
+                    let easter = easter_day(y);
+                    let window_start = (easter - window as i64).max(start);
+                    let window_end = easter.min(end);
+                    days += (window_end - window_start).max(0);
+                }
+                days as f64 / window as f64
+            })
+            .collect()
+    }
+
+    // Number of user supplied holidays in each period
+    pub fn holiday_regressor(&self, holidays: &[String], horizon: usize) -> Vec<f64> {
+        let holidays = parse_holidays(holidays);
+        self.extended_ordinal(horizon)
+            .iter()
+            .map(|o| {
+                let (start, end) = self.day_span(*o);
+                holiday_days(&holidays, start, end).len() as f64
+            })
+            .collect()
+    }
+
+    // Monday to Friday days that are not holidays
+    pub fn working_day_regressor(&self, holidays: &[String], horizon: usize) -> Vec<f64> {
+        let holidays = parse_holidays(holidays);
+        self.extended_ordinal(horizon)
+            .iter()
+            .map(|o| {
+                let (start, end) = self.day_span(*o);
+                let off = holiday_days(&holidays, start, end).iter().filter(|d| weekday(**d) < 5).count();
+                let weekdays = (start..end).filter(|d| weekday(*d) < 5).count();
+                (weekdays - off) as f64
+            })
+            .collect()
+    }
+}
+
+#[wasm_bindgen]
+impl TimeIndex {
+    pub fn calculate_trading_day_regressor(&self, model: String, horizon: usize) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.trading_day_regressor(&model, horizon)).unwrap()
+    }
+    pub fn calculate_easter_regressor(&self, window: usize, horizon: usize) -> Vec<f64> {
+        self.easter_regressor(window, horizon)
+    }
+    pub fn calculate_holiday_regressor(&self, holidays: Vec<String>, horizon: usize) -> Vec<f64> {
+        self.holiday_regressor(&holidays, horizon)
+    }
+    pub fn calculate_working_days(&self, holidays: Vec<String>, horizon: usize) -> Vec<f64> {
+        self.working_day_regressor(&holidays, horizon)
+    }
+}
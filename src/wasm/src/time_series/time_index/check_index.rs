@@ -0,0 +1,50 @@
+use wasm_bindgen::prelude::*;
+use crate::TimeIndex;
+
+#[wasm_bindgen]
+impl TimeIndex {
+    // Positions of labels that repeat an earlier timestamp
+    pub fn check_duplicate(&mut self) -> Vec<i32> {
+        if self.get_frequency() == "unknown" {
+            panic!("Time labels could not be parsed");
+        }
+        let ordinal = self.get_ordinal();
+        let mut duplicate_index: Vec<i32> = Vec::new();
+        for i in 1..ordinal.len() {
+            if ordinal[..i].contains(&ordinal[i]) {
+                duplicate_index.push(i as i32);
+            }
+        }
+        self.set_duplicate_index(duplicate_index.clone());
+        duplicate_index
+    }
+
+    // Labels absent from the regular grid between the first and last timestamp
+    pub fn check_missing(&mut self) -> Vec<String> {
+        if self.get_frequency() == "unknown" {
+            panic!("Time labels could not be parsed");
+        }
+        let step = self.get_step() as i64;
+        let mut sorted = self.get_ordinal().clone();
+        sorted.sort();
+        sorted.dedup();
+        let mut missing_time: Vec<String> = Vec::new();
+        for pair in sorted.windows(2) {
+            let mut ordinal = pair[0] + step;
+            while ordinal < pair[1] {
+                missing_time.push(self.format_label(ordinal));
+                ordinal += step;
+            }
+        }
+        self.set_missing_time(missing_time.clone());
+        missing_time
+    }
+
+    // Labels are increasing, unique and evenly spaced
+    pub fn is_regular(&self) -> bool {
+        let step = self.get_step() as i64;
+        self.get_frequency() != "unknown"
+            && self.get_frequency() != "irregular"
+            && self.get_ordinal().windows(2).all(|w| w[1] - w[0] == step)
+    }
+}
@@ -0,0 +1,230 @@
+use wasm_bindgen::prelude::*;
+use crate::TimeIndex;
+use crate::time_series::time_index::calendar::*;
+use crate::time_series::time_index::parse_label::*;
+
+// Most common positive gap between sorted ordinals, the smallest on ties and 1 when there are none
+fn typical_gap(ordinal: &[i64]) -> i64 {
+    let mut sorted = ordinal.to_vec();
+    sorted.sort();
+    let mut gaps: Vec<i64> = sorted.windows(2).map(|w| w[1] - w[0]).filter(|g| *g > 0).collect();
+    gaps.sort();
+    let mut best = (1, 0);
+    for chunk in gaps.chunk_by(|a, b| a == b) {
+        if chunk.len() > best.1 {
+            best = (chunk[0], chunk.len());
+        }
+    }
+    best.0
+}
+
+// Nominal first value and length of a Define Date/Time cycle component
+fn nominal_cycle(letter: &str) -> (i64, i64) {
+    match letter {
+        "d" => (1, 7),
+        "h" => (0, 24),
+        "m" => (0, 60),
+        "s" => (0, 60),
+        _ => (1, 0),
+    }
+}
+
+#[wasm_bindgen]
+impl TimeIndex {
+    pub fn detect_frequency(&mut self) -> String {
+        let time = self.get_time();
+        self.set_index("unknown", "index", Vec::new());
+        self.set_frequency("unknown", 0, 1);
+        self.set_duplicate_index(Vec::new());
+        self.set_missing_time(Vec::new());
+        if time.is_empty() {
+            return self.get_frequency();
+        }
+
+        let day_first = day_first(&time);
+        let parsed: Option<Vec<ParsedLabel>> = time.iter().map(|t| parse_label(t, day_first)).collect();
+        let mut parsed = match parsed {
+            Some(parsed) => parsed,
+            None => return self.get_frequency(),
+        };
+        // Observation numbers past 999 read like years
+        if parsed.iter().any(|p| matches!(p, ParsedLabel::Index(_))) {
+            for p in parsed.iter_mut() {
+                if let ParsedLabel::Year(y) = p {
+                    *p = ParsedLabel::Index(*y);
+                }
+            }
+        }
+        // Every label has to be written in the same format
+        if parsed.iter().any(|p| std::mem::discriminant(p) != std::mem::discriminant(&parsed[0])) {
+            return self.get_frequency();
+        }
+
+        let first = time[0].trim().to_lowercase();
+        let starts_with_digit = first.starts_with(|c: char| c.is_ascii_digit());
+        let ordinal: Vec<i64> = parsed
+            .iter()
+            .map(|p| match p {
+                ParsedLabel::Year(y) => *y,
+                ParsedLabel::YearQuarter(y, q) => y * 4 + q - 1,
+                ParsedLabel::YearMonth(y, m) => y * 12 + m - 1,
+                ParsedLabel::YearWeek(y, w) => iso_week_start(*y, *w),
+                ParsedLabel::Date(d) => *d,
+                ParsedLabel::DateTime(m) => *m,
+                ParsedLabel::Index(i) => *i,
+                _ => 0,
+            })
+            .collect();
+        match &parsed[0] {
+            ParsedLabel::Year(_) => self.set_index("year", "year", ordinal),
+            ParsedLabel::YearQuarter(_, _) => {
+                self.set_index(if starts_with_digit { "year_quarter" } else { "quarter_year" }, "quarter", ordinal)
+            }
+            ParsedLabel::YearMonth(_, _) => {
+                let named = first.chars().any(|c| c.is_ascii_alphabetic());
+                self.set_index(if named { "month_year" } else { "year_month" }, "month", ordinal)
+            }
+            ParsedLabel::YearWeek(_, _) => self.set_index("iso_week", "day", ordinal),
+            ParsedLabel::Date(d) => {
+                self.set_anchor(civil_from_days(*d));
+                self.set_index("date", "day", ordinal);
+            }
+            ParsedLabel::DateTime(m) => {
+                self.set_anchor(civil_from_days(m.div_euclid(1440)));
+                let clock_only = !first[..first.find(':').unwrap_or(0)].contains([' ', 't']);
+                self.set_index(if clock_only { "clock" } else { "datetime" }, "minute", ordinal);
+            }
+            ParsedLabel::Index(_) => self.set_index("index", "index", ordinal),
+            ParsedLabel::Cycle(_) => self.index_cycle(&time, &parsed),
+            ParsedLabel::MonthDay(_, _) => return self.get_frequency(),
+        }
+
+        // Dates are regrouped on the coarsest calendar unit their spacing allows
+        let mut ordinal = self.get_ordinal().clone();
+        if self.get_label_format() == "date" {
+            let gap = typical_gap(&ordinal);
+            let to_month = |d: &i64| {
+                let (y, m, _) = civil_from_days(*d);
+                y * 12 + m - 1
+            };
+            if gap == 1 && ordinal.len() >= 10 && ordinal.iter().all(|d| weekday(*d) < 5) {
+                ordinal = ordinal.iter().map(|d| business_from_days(*d)).collect();
+                self.set_index("date", "business_day", ordinal.clone());
+            } else if (28..=31).contains(&gap) || (89..=92).contains(&gap) || (181..=184).contains(&gap) {
+                ordinal = ordinal.iter().map(to_month).collect();
+                self.set_index("date", "month", ordinal.clone());
+            } else if (365..=366).contains(&gap) {
+                ordinal = ordinal.iter().map(|d| civil_from_days(*d).0).collect();
+                self.set_index("date", "year", ordinal.clone());
+            }
+        }
+
+        let step = typical_gap(&ordinal);
+        let (frequency, period) = match (self.get_unit().as_str(), step) {
+            ("year", 1) => ("yearly", 1),
+            ("quarter", 1) | ("month", 3) => ("quarterly", 4),
+            ("month", 6) => ("half_yearly", 2),
+            ("month", 1) => ("monthly", 12),
+            ("day", 7) => ("weekly", 52),
+            ("day", 1) | ("minute", 1440) => ("daily", 7),
+            ("business_day", 1) => ("business_daily", 5),
+            ("minute", 60) => ("hourly", 24),
+            ("minute", s) if 1440 % s == 0 => ("minutely", (1440 / s) as i32),
+            ("cycle", 1) => ("cyclic", *self.get_cycle_radix().last().unwrap_or(&0) as i32),
+            ("index", 1) => ("index", 0),
+            _ => ("irregular", 0),
+        };
+        self.set_frequency(frequency, period, step);
+        self.get_frequency()
+    }
+}
+
+impl TimeIndex {
+    // Mixed radix ordinals for Define Date/Time cycles such as weeks and days
+    fn index_cycle(&mut self, time: &[String], parsed: &[ParsedLabel]) {
+        let letter: Vec<String> = tokenize(&time[0].to_lowercase())
+            .into_iter()
+            .filter(|t| !t.chars().all(|c| c.is_ascii_digit()))
+            .collect();
+        let values: Vec<Vec<i64>> = parsed
+            .iter()
+            .map(|p| match p {
+                ParsedLabel::Cycle(v) => v.clone(),
+                _ => Vec::new(),
+            })
+            .collect();
+        if values.iter().any(|v| v.len() != letter.len()) {
+            return;
+        }
+        let k = letter.len();
+        let mut base = vec![0; k];
+        let mut radix = vec![0; k];
+        for j in 1..k {
+            let (nominal_base, nominal_radix) = nominal_cycle(&letter[j]);
+            let min = values.iter().map(|v| v[j]).min().unwrap_or(0);
+            let max = values.iter().map(|v| v[j]).max().unwrap_or(0);
+            base[j] = min.min(nominal_base);
+            // A cycle seen only once may be incomplete, so its nominal length is trusted instead
+            let outer_changes = values.iter().any(|v| v[j - 1] != values[0][j - 1]);
+            radix[j] = if outer_changes { max - base[j] + 1 } else { (max - base[j] + 1).max(nominal_radix) };
+        }
+        let ordinal: Vec<i64> = values
+            .iter()
+            .map(|v| (1..k).fold(v[0], |acc, j| acc * radix[j] + v[j] - base[j]))
+            .collect();
+        self.set_cycle(letter.iter().map(|l| l.to_uppercase()).collect(), base, radix);
+        self.set_index("cycle", "cycle", ordinal);
+    }
+
+    // Write an ordinal back as a label in the format of the input
+    pub fn format_label(&self, ordinal: i64) -> String {
+        let unit = self.get_unit();
+        let (_, anchor_month, anchor_day) = self.get_anchor();
+        let date = |days: i64| {
+            let (y, m, d) = civil_from_days(days);
+            format!("{:04}-{:02}-{:02}", y, m, d)
+        };
+        match self.get_label_format().as_str() {
+            "year" | "index" => ordinal.to_string(),
+            "year_quarter" => format!("{} Q{}", ordinal.div_euclid(4), ordinal.rem_euclid(4) + 1),
+            "quarter_year" => format!("Q{} {}", ordinal.rem_euclid(4) + 1, ordinal.div_euclid(4)),
+            "year_month" => format!("{}-{:02}", ordinal.div_euclid(12), ordinal.rem_euclid(12) + 1),
+            "month_year" => format!("{} {}", MONTH_NAMES[ordinal.rem_euclid(12) as usize].to_uppercase(), ordinal.div_euclid(12)),
+            "iso_week" => {
+                let (y, w) = iso_week(ordinal);
+                format!("{}-W{:02}", y, w)
+            }
+            "date" => match unit.as_str() {
+                "business_day" => date(days_from_business(ordinal)),
+                "month" => {
+                    let (y, m) = (ordinal.div_euclid(12), ordinal.rem_euclid(12) + 1);
+                    date(days_from_civil(y, m, anchor_day.min(days_in_month(y, m))))
+                }
+                "year" => date(days_from_civil(ordinal, anchor_month, anchor_day.min(days_in_month(ordinal, anchor_month)))),
+                _ => date(ordinal),
+            },
+            "datetime" => {
+                let minute = ordinal.rem_euclid(1440);
+                format!("{} {:02}:{:02}", date(ordinal.div_euclid(1440)), minute / 60, minute % 60)
+            }
+            "clock" => {
+                let minute = ordinal.rem_euclid(1440);
+                format!("{:02}:{:02}", minute / 60, minute % 60)
+            }
+            "cycle" => {
+                let letter = self.get_cycle_letter();
+                let base = self.get_cycle_base();
+                let radix = self.get_cycle_radix();
+                let mut value = vec![0; letter.len()];
+                let mut rest = ordinal;
+                for j in (1..letter.len()).rev() {
+                    value[j] = rest.rem_euclid(radix[j]) + base[j];
+                    rest = rest.div_euclid(radix[j]);
+                }
+                value[0] = rest;
+                letter.iter().zip(value.iter()).map(|(l, v)| format!("{}{}", l, v)).collect::<Vec<String>>().join(" ")
+            }
+            _ => String::new(),
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use wasm_bindgen::prelude::*;
+use crate::TimeIndex;
+
+#[wasm_bindgen]
+impl TimeIndex {
+    // Labels of the periods following the last timestamp, e.g. for forecasts
+    pub fn generate_future_label(&self, horizon: usize) -> Vec<String> {
+        if self.get_frequency() == "unknown" {
+            panic!("Time labels could not be parsed");
+        }
+        let step = self.get_step() as i64;
+        let last = *self.get_ordinal().iter().max().unwrap();
+        (1..=horizon as i64).map(|h| self.format_label(last + h * step)).collect()
+    }
+}
+
+impl TimeIndex {
+    // Ordinals of the observations followed by the next horizon periods
+    pub fn extended_ordinal(&self, horizon: usize) -> Vec<i64> {
+        let step = self.get_step() as i64;
+        let mut ordinal = self.get_ordinal().clone();
+        let last = ordinal.iter().copied().max().unwrap_or(0);
+        ordinal.extend((1..=horizon as i64).map(|h| last + h * step));
+        ordinal
+    }
+}
@@ -0,0 +1,9 @@
+pub mod time_index;
+pub mod calendar;
+pub mod parse_label;
+pub mod frequency;
+pub mod check_index;
+pub mod future_label;
+pub mod calendar_regressor;
+
+pub use time_index::TimeIndex;
\ No newline at end of file
@@ -0,0 +1,161 @@
+use crate::time_series::time_index::calendar::*;
+
+// A time label reduced to its calendar components
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParsedLabel {
+    Year(i64),
+    YearQuarter(i64, i64),
+    YearMonth(i64, i64),
+    YearWeek(i64, i64),
+    Date(i64),          // Days since 1970-01-01
+    DateTime(i64),      // Minutes since 1970-01-01
+    MonthDay(i64, i64), // Date recurring every year, e.g. "12-25"
+    Cycle(Vec<i64>),    // Define Date/Time cycles, e.g. "W1 D3" or "D2 H14"
+    Index(i64),
+}
+
+// Split a label into runs of letters and runs of digits
+pub fn tokenize(label: &str) -> Vec<String> {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for c in label.chars() {
+        if c.is_ascii_alphanumeric() {
+            if let Some(last) = current.chars().last() {
+                if last.is_ascii_digit() != c.is_ascii_digit() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            current.push(c);
+        } else if !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+// Numeric dates are read as day/month/year when any label starts with a day above 12
+pub fn day_first(time: &[String]) -> bool {
+    time.iter().any(|label| {
+        let tokens = tokenize(label);
+        tokens.len() >= 3
+            && tokens[..3].iter().all(|t| t.chars().all(|c| c.is_ascii_digit()))
+            && tokens[0].len() <= 2
+            && tokens[0].parse::<i64>().unwrap_or(0) > 12
+    })
+}
+
+fn month_from_name(word: &str) -> Option<i64> {
+    if word.len() < 3 {
+        return None;
+    }
+    MONTH_NAMES.iter().position(|m| *m == &word[..3]).map(|m| m as i64 + 1)
+}
+
+fn date_from_parts(year: i64, month: i64, day: i64) -> Option<i64> {
+    if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day))
+}
+
+// Parse one label, None when the format is not recognised
+pub fn parse_label(label: &str, day_first: bool) -> Option<ParsedLabel> {
+    let label = label.trim().to_lowercase();
+    if label.is_empty() {
+        return None;
+    }
+
+    // Date and time, e.g. "2020-01-31 14:00" or "2020-01-31T14:00:00"; a bare clock time falls on day 0
+    if let Some(colon) = label.find(':') {
+        let (day, clock) = match label[..colon].rfind([' ', 't']) {
+            Some(split) => match parse_label(&label[..split], day_first)? {
+                ParsedLabel::Date(day) => (day, &label[split + 1..]),
+                _ => return None,
+            },
+            None => (0, label.as_str()),
+        };
+        let clock: Vec<i64> = clock
+            .split(':')
+            .take(2)
+            .map(|p| p.trim_end_matches(|c: char| !c.is_ascii_digit()).parse().ok())
+            .collect::<Option<Vec<i64>>>()?;
+        if clock.len() < 2 || clock[0] > 23 || clock[1] > 59 {
+            return None;
+        }
+        return Some(ParsedLabel::DateTime(day * 1440 + clock[0] * 60 + clock[1]));
+    }
+
+    let tokens = tokenize(&label);
+    let is_number = |t: &String| t.chars().all(|c| c.is_ascii_digit());
+    let numbers: Vec<i64> = tokens.iter().filter(|t| is_number(t)).map(|t| t.parse().unwrap_or(0)).collect();
+    let words: Vec<&String> = tokens.iter().filter(|t| !is_number(t)).collect();
+    let year_position = tokens.iter().position(|t| t.len() == 4 && is_number(t));
+    let year = year_position.map(|i| tokens[i].parse::<i64>().unwrap_or(0));
+
+    // Purely numeric labels
+    if words.is_empty() {
+        return match numbers.len() {
+            1 if year.is_some() => Some(ParsedLabel::Year(numbers[0])),
+            1 => Some(ParsedLabel::Index(numbers[0])),
+            2 => match year_position {
+                Some(0) if (1..=12).contains(&numbers[1]) => Some(ParsedLabel::YearMonth(numbers[0], numbers[1])),
+                Some(1) if (1..=12).contains(&numbers[0]) => Some(ParsedLabel::YearMonth(numbers[1], numbers[0])),
+                None if (1..=12).contains(&numbers[0]) && (1..=31).contains(&numbers[1]) => Some(ParsedLabel::MonthDay(numbers[0], numbers[1])),
+                _ => None,
+            },
+            3 => match year_position {
+                Some(0) => date_from_parts(numbers[0], numbers[1], numbers[2]).map(ParsedLabel::Date),
+                Some(2) if day_first => date_from_parts(numbers[2], numbers[1], numbers[0]).map(ParsedLabel::Date),
+                Some(2) => date_from_parts(numbers[2], numbers[0], numbers[1]).map(ParsedLabel::Date),
+                _ => None,
+            },
+            _ => None,
+        };
+    }
+
+    // Number written right after the first word, e.g. the 1 of "Q1"
+    let word_position = tokens.iter().position(|t| !is_number(t)).unwrap_or(0);
+    let after_word = tokens.get(word_position + 1).and_then(|t| t.parse::<i64>().ok());
+
+    if let Some(year) = year {
+        if words.len() == 1 && numbers.len() == 2 {
+            // Year and quarter, e.g. "1990 Q1" or "Q1 1990"
+            if words[0] == "q" {
+                return after_word.filter(|q| (1..=4).contains(q)).map(|q| ParsedLabel::YearQuarter(year, q));
+            }
+            // ISO week, e.g. "2020-W05"
+            if words[0] == "w" {
+                return after_word.filter(|w| (1..=53).contains(w)).map(|w| ParsedLabel::YearWeek(year, w));
+            }
+        }
+        // Month names, e.g. "JAN 1990" or "15 Jan 2020"
+        if words.len() == 1 {
+            let month = month_from_name(words[0])?;
+            return match numbers.len() {
+                1 => Some(ParsedLabel::YearMonth(year, month)),
+                2 => {
+                    let day = tokens
+                        .iter()
+                        .enumerate()
+                        .find(|(i, t)| Some(*i) != year_position && is_number(t))
+                        .and_then(|(_, t)| t.parse().ok())?;
+                    date_from_parts(year, month, day).map(ParsedLabel::Date)
+                }
+                _ => None,
+            };
+        }
+        return None;
+    }
+
+    // Nested cycles without a calendar year, e.g. "W1 D3", "D 2 H 14"
+    let alternating = tokens.len() >= 4
+        && tokens.len().is_multiple_of(2)
+        && tokens.iter().enumerate().all(|(i, t)| if i % 2 == 0 { t.len() == 1 && !is_number(t) } else { is_number(t) });
+    if alternating {
+        return Some(ParsedLabel::Cycle(numbers));
+    }
+    None
+}
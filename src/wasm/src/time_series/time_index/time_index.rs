@@ -0,0 +1,125 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct TimeIndex {
+    time: Vec<String>,
+    time_header: String,
+    label_format: String,      // "year", "year_quarter", "quarter_year", "month_year", "year_month", "iso_week", "date", "datetime", "clock", "cycle", "index" or "unknown"
+    frequency: String,         // "yearly", "quarterly", "monthly", "weekly", "daily", "business_daily", "hourly", "minutely", "cyclic", "index", "irregular" or "unknown"
+    period: i32,               // Observations per seasonal cycle, 0 when unknown
+    step: i64,                 // Spacing of consecutive labels in units of the ordinal
+    unit: String,              // Unit of the ordinal: "year", "quarter", "month", "day", "business_day", "minute", "cycle" or "index"
+    ordinal: Vec<i64>,         // Position of each label on the time grid
+    anchor: (i64, i64, i64),   // First calendar date, used to write dates at lower frequencies
+    cycle_letter: Vec<String>, // Letters of Define Date/Time cycles, e.g. ["W", "D"]
+    cycle_base: Vec<i64>,      // First value of each cycle component
+    cycle_radix: Vec<i64>,     // Length of each nested cycle
+    duplicate_index: Vec<i32>,
+    missing_time: Vec<String>,
+}
+
+#[wasm_bindgen]
+impl TimeIndex {
+    #[wasm_bindgen(constructor)]
+    pub fn new(time: Vec<String>, time_header: String) -> TimeIndex {
+        let mut time_index = TimeIndex {
+            time,
+            time_header,
+            label_format: "unknown".to_string(),
+            frequency: "unknown".to_string(),
+            period: 0,
+            step: 1,
+            unit: "index".to_string(),
+            ordinal: Vec::new(),
+            anchor: (1970, 1, 1),
+            cycle_letter: Vec::new(),
+            cycle_base: Vec::new(),
+            cycle_radix: Vec::new(),
+            duplicate_index: Vec::new(),
+            missing_time: Vec::new(),
+        };
+        time_index.detect_frequency();
+        time_index
+    }
+
+    // Getters
+    pub fn get_time(&self) -> Vec<String> {
+        self.time.clone()
+    }
+    pub fn get_time_header(&self) -> String {
+        self.time_header.clone()
+    }
+    pub fn get_label_format(&self) -> String {
+        self.label_format.clone()
+    }
+    pub fn get_frequency(&self) -> String {
+        self.frequency.clone()
+    }
+    pub fn get_period(&self) -> i32 {
+        self.period
+    }
+    pub fn get_step(&self) -> i32 {
+        self.step as i32
+    }
+    pub fn get_unit(&self) -> String {
+        self.unit.clone()
+    }
+    pub fn get_duplicate_index(&self) -> Vec<i32> {
+        self.duplicate_index.clone()
+    }
+    pub fn get_missing_time(&self) -> Vec<String> {
+        self.missing_time.clone()
+    }
+
+    // Setters
+    pub fn set_time(&mut self, time: Vec<String>) {
+        self.time = time;
+        self.detect_frequency();
+    }
+    pub fn set_time_header(&mut self, time_header: String) {
+        self.time_header = time_header;
+    }
+    pub fn set_duplicate_index(&mut self, duplicate_index: Vec<i32>) {
+        self.duplicate_index = duplicate_index;
+    }
+    pub fn set_missing_time(&mut self, missing_time: Vec<String>) {
+        self.missing_time = missing_time;
+    }
+}
+
+impl TimeIndex {
+    pub fn get_ordinal(&self) -> &Vec<i64> {
+        &self.ordinal
+    }
+    pub fn get_anchor(&self) -> (i64, i64, i64) {
+        self.anchor
+    }
+    pub fn get_cycle_letter(&self) -> &Vec<String> {
+        &self.cycle_letter
+    }
+    pub fn get_cycle_base(&self) -> &Vec<i64> {
+        &self.cycle_base
+    }
+    pub fn get_cycle_radix(&self) -> &Vec<i64> {
+        &self.cycle_radix
+    }
+
+    pub fn set_index(&mut self, label_format: &str, unit: &str, ordinal: Vec<i64>) {
+        self.label_format = label_format.to_string();
+        self.unit = unit.to_string();
+        self.ordinal = ordinal;
+    }
+    pub fn set_frequency(&mut self, frequency: &str, period: i32, step: i64) {
+        self.frequency = frequency.to_string();
+        self.period = period;
+        self.step = step;
+    }
+    pub fn set_anchor(&mut self, anchor: (i64, i64, i64)) {
+        self.anchor = anchor;
+    }
+    pub fn set_cycle(&mut self, cycle_letter: Vec<String>, cycle_base: Vec<i64>, cycle_radix: Vec<i64>) {
+        self.cycle_letter = cycle_letter;
+        self.cycle_base = cycle_base;
+        self.cycle_radix = cycle_radix;
+    }
+}
@@ -0,0 +1,28 @@
+use wasm_bindgen::prelude::*;
+use crate::Transformation;
+use crate::time_series::transformation::power_transform::*;
+use crate::time_series::transformation::lambda_estimation::*;
+
+#[wasm_bindgen]
+impl Transformation {
+    // Estimate lambda by "guerrero" or "likelihood"; period is the seasonal period of the data
+    pub fn estimate_lambda(&mut self, lambda_method: String, period: usize) -> f64 {
+        let lambda = estimate_lambda(&self.get_data(), &self.get_method(), &lambda_method, self.get_lambda(), period);
+        self.set_lambda(lambda);
+        lambda
+    }
+
+    pub fn calculate_transformation(&mut self) -> Vec<f64> {
+        let method = self.get_method();
+        let lambda = self.get_lambda();
+        let transformed_data: Vec<f64> = self.get_data().iter().map(|x| transform(&method, *x, lambda)).collect();
+        self.set_transformed_data(transformed_data.clone());
+        transformed_data
+    }
+
+    // Values on the transformed scale back to the original scale; with variances
+    // of those values the result is bias adjusted to the mean, otherwise it is the median
+    pub fn calculate_back_transformation(&self, values: Vec<f64>, variance: Vec<f64>) -> Vec<f64> {
+        back_transform(&self.get_method(), self.get_lambda(), &values, &variance)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use nalgebra::{DMatrix, DVector};
+use crate::time_series::transformation::power_transform::*;
+
+// Grid search over [lower, upper] followed by golden section refinement
+fn maximize<F: Fn(f64) -> f64>(objective: F, lower: f64, upper: f64) -> f64 {
+    let width = (upper - lower) / 100.0;
+    let mut best = lower;
+    let mut best_value = f64::NEG_INFINITY;
+    for k in 0..=100 {
+        let lambda = lower + k as f64 * width;
+        let value = objective(lambda);
+        if value > best_value {
+            best_value = value;
+            best = lambda;
+        }
+    }
+    let ratio = (5.0_f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = ((best - width).max(lower), (best + width).min(upper));
+    for _ in 0..40 {
+        let x1 = b - ratio * (b - a);
+        let x2 = a + ratio * (b - a);
+        if objective(x1) > objective(x2) {
+            b = x2;
+        } else {
+            a = x1;
+        }
+    }
+    (a + b) / 2.0
+}
+
+fn mean_sd(x: &[f64]) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean = x.iter().sum::<f64>() / n;
+    let variance = x.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance.sqrt())
+}
+
+// Guerrero's method: lambda making sd / mean^(1 - lambda) most stable across blocks of one period
+pub fn guerrero_lambda(data: &[f64], period: usize, lower: f64, upper: f64) -> f64 {
+    let data: Vec<f64> = data.iter().copied().filter(|x| !x.is_nan()).collect();
+    if data.iter().any(|x| *x <= 0.0) {
+        panic!("Guerrero's method needs positive data");
+    }
+    let period = period.max(2);
+    let blocks = data.len() / period;
+    if blocks < 2 {
+        panic!("Guerrero's method needs at least two full periods");
+    }
+    // The most recent complete blocks are used
+    let start = data.len() - blocks * period;
+    let stats: Vec<(f64, f64)> = data[start..].chunks(period).map(mean_sd).collect();
+    let coefficient_of_variation = |lambda: f64| {
+        let ratio: Vec<f64> = stats.iter().map(|(mean, sd)| sd / mean.powf(1.0 - lambda)).collect();
+        let (mean, sd) = mean_sd(&ratio);
+        sd / mean
+    };
+    maximize(|lambda| -coefficient_of_variation(lambda), lower, upper)
+}
+
+// Profile log-likelihood of the transformed data around a linear trend, plus seasonal dummies when period > 1
+pub fn profile_log_likelihood(data: &[f64], method: &str, lambda: f64, period: usize) -> f64 {
+    let x: Vec<f64> = data.iter().copied().filter(|x| !x.is_nan()).collect();
+    let n = x.len();
+    let seasons = period.saturating_sub(1);
+    let design = DMatrix::from_fn(n, 2 + seasons, |i, j| match j {
+        0 => 1.0,
+        1 => i as f64,
+        _ => if i % period == j - 1 { 1.0 } else { 0.0 },
+    });
+    let y = DVector::from_iterator(n, x.iter().map(|v| transform(method, *v, lambda)));
+    let beta = match design.clone().svd(true, true).solve(&y, 1e-12) {
+        Ok(beta) => beta,
+        Err(_) => return f64::NEG_INFINITY,
+    };
+    let residual = &y - &design * beta;
+    let sigma2 = residual.norm_squared() / n as f64;
+    let jacobian: f64 = match method {
+        "box_cox" => x.iter().map(|v| v.ln()).sum(),
+        _ => x.iter().map(|v| v.signum() * v.abs().ln_1p()).sum(),
+    };
+    -0.5 * n as f64 * sigma2.ln() + (lambda - 1.0) * jacobian
+}
+
+pub fn likelihood_lambda(data: &[f64], method: &str, period: usize, lower: f64, upper: f64) -> f64 {
+    if method == "box_cox" && data.iter().any(|x| *x <= 0.0) {
+        panic!("Box-Cox transformation needs positive data");
+    }
+    maximize(|lambda| profile_log_likelihood(data, method, lambda, period), lower, upper)
+}
+
+// Lambda by "guerrero", "likelihood" or the given value for "manual"
+pub fn estimate_lambda(data: &[f64], method: &str, lambda_method: &str, lambda: f64, period: usize) -> f64 {
+    match (method, lambda_method) {
+        ("none", _) => 1.0,
+        (_, "manual") => lambda,
+        ("box_cox", "guerrero") => guerrero_lambda(data, period, -1.0, 2.0),
+        (_, "guerrero") => panic!("Guerrero's method needs the Box-Cox transformation"),
+        (_, "likelihood") => likelihood_lambda(data, method, period, -1.0, 2.0),
+        _ => panic!("Unknown lambda estimation method: {}", lambda_method),
+    }
+}
@@ -0,0 +1,6 @@
+pub mod transformation;
+pub mod power_transform;
+pub mod lambda_estimation;
+pub mod calculate_transformation;
+
+pub use transformation::Transformation;
\ No newline at end of file
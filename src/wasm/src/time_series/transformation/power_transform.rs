@@ -0,0 +1,103 @@
+// Lambdas closer than this to a branch point use the logarithmic form
+const LAMBDA_TOLERANCE: f64 = 1e-8;
+
+// Box-Cox transform, (x^lambda - 1) / lambda or ln(x) at lambda = 0; NaN outside x > 0
+pub fn box_cox(x: f64, lambda: f64) -> f64 {
+    if x <= 0.0 {
+        f64::NAN
+    } else if lambda.abs() < LAMBDA_TOLERANCE {
+        x.ln()
+    } else {
+        (x.powf(lambda) - 1.0) / lambda
+    }
+}
+
+pub fn inverse_box_cox(y: f64, lambda: f64) -> f64 {
+    if lambda.abs() < LAMBDA_TOLERANCE {
+        y.exp()
+    } else {
+        (lambda * y + 1.0).powf(1.0 / lambda)
+    }
+}
+
+// Yeo-Johnson transform, defined for any real x
+pub fn yeo_johnson(x: f64, lambda: f64) -> f64 {
+    if x >= 0.0 {
+        if lambda.abs() < LAMBDA_TOLERANCE {
+            x.ln_1p()
+        } else {
+            ((x + 1.0).powf(lambda) - 1.0) / lambda
+        }
+    } else if (lambda - 2.0).abs() < LAMBDA_TOLERANCE {
+        -(-x).ln_1p()
+    } else {
+        -((1.0 - x).powf(2.0 - lambda) - 1.0) / (2.0 - lambda)
+    }
+}
+
+pub fn inverse_yeo_johnson(y: f64, lambda: f64) -> f64 {
+    if y >= 0.0 {
+        if lambda.abs() < LAMBDA_TOLERANCE {
+            y.exp_m1()
+        } else {
+            (lambda * y + 1.0).powf(1.0 / lambda) - 1.0
+        }
+    } else if (lambda - 2.0).abs() < LAMBDA_TOLERANCE {
+        1.0 - (-y).exp()
+    } else {
+        1.0 - (1.0 - (2.0 - lambda) * y).powf(1.0 / (2.0 - lambda))
+    }
+}
+
+// Second derivative of the inverse transform, used for the bias adjustment
+fn inverse_curvature(method: &str, y: f64, lambda: f64) -> f64 {
+    let positive_branch = method == "box_cox" || y >= 0.0;
+    if positive_branch {
+        if lambda.abs() < LAMBDA_TOLERANCE {
+            y.exp()
+        } else {
+            (1.0 - lambda) * (lambda * y + 1.0).powf(1.0 / lambda - 2.0)
+        }
+    } else if (lambda - 2.0).abs() < LAMBDA_TOLERANCE {
+        -(-y).exp()
+    } else {
+        (1.0 - lambda) * (1.0 - (2.0 - lambda) * y).powf(1.0 / (2.0 - lambda) - 2.0)
+    }
+}
+
+pub fn transform(method: &str, x: f64, lambda: f64) -> f64 {
+    match method {
+        "none" => x,
+        "box_cox" => box_cox(x, lambda),
+        "yeo_johnson" => yeo_johnson(x, lambda),
+        _ => panic!("Unknown transformation: {}", method),
+    }
+}
+
+// Back to the original scale; a positive variance of y on the transformed scale
+// gives the mean instead of the median (second order Taylor bias adjustment)
+pub fn inverse_transform(method: &str, y: f64, lambda: f64, variance: f64) -> f64 {
+    let median = match method {
+        "none" => return y,
+        "box_cox" => inverse_box_cox(y, lambda),
+        "yeo_johnson" => inverse_yeo_johnson(y, lambda),
+        _ => panic!("Unknown transformation: {}", method),
+    };
+    if variance > 0.0 {
+        median + 0.5 * variance * inverse_curvature(method, y, lambda)
+    } else {
+        median
+    }
+}
+
+// Element-wise inverse_transform; an empty variance gives medians
+pub fn back_transform(method: &str, lambda: f64, values: &[f64], variance: &[f64]) -> Vec<f64> {
+    if !variance.is_empty() && variance.len() != values.len() {
+        panic!("Variance must be empty or match the values");
+    }
+    values
+        .iter()
+        .enumerate()
+        .map(|(i, y)| inverse_transform(method, *y, lambda, variance.get(i).copied().unwrap_or(0.0)))
+        .collect()
+}
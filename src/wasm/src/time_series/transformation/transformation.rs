@@ -0,0 +1,58 @@
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+pub struct Transformation {
+    data: Vec<f64>,
+    data_header: String,
+    method: String, // "box_cox", "yeo_johnson" or "none"
+    lambda: f64,
+    transformed_data: Vec<f64>,
+}
+
+#[wasm_bindgen]
+impl Transformation {
+    #[wasm_bindgen(constructor)]
+    pub fn new(data: Vec<f64>, data_header: String, method: String) -> Transformation {
+        Transformation {
+            data,
+            data_header,
+            method,
+            lambda: 1.0,
+            transformed_data: Vec::new(),
+        }
+    }
+
+    // Getters
+    pub fn get_data(&self) -> Vec<f64> {
+        self.data.clone()
+    }
+    pub fn get_data_header(&self) -> String {
+        self.data_header.clone()
+    }
+    pub fn get_method(&self) -> String {
+        self.method.clone()
+    }
+    pub fn get_lambda(&self) -> f64 {
+        self.lambda
+    }
+    pub fn get_transformed_data(&self) -> Vec<f64> {
+        self.transformed_data.clone()
+    }
+
+    // Setters
+    pub fn set_data(&mut self, data: Vec<f64>) {
+        self.data = data;
+    }
+    pub fn set_data_header(&mut self, data_header: String) {
+        self.data_header = data_header;
+    }
+    pub fn set_method(&mut self, method: String) {
+        self.method = method;
+    }
+    pub fn set_lambda(&mut self, lambda: f64) {
+        self.lambda = lambda;
+    }
+    pub fn set_transformed_data(&mut self, transformed_data: Vec<f64>) {
+        self.transformed_data = transformed_data;
+    }
+}
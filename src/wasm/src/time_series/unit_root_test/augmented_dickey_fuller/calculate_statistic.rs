@@ -2,6 +2,7 @@ use wasm_bindgen::prelude::*;
 use crate::AugmentedDickeyFuller;
 use crate::{calculate_p_value, calculate_critical_values, MultipleLinearRegression};
 use crate::{first_difference, second_difference};
+use crate::time_series::missing_value::complete_cases::complete_cases;
 
 #[wasm_bindgen]
 impl AugmentedDickeyFuller {
@@ -12,7 +13,7 @@ impl AugmentedDickeyFuller {
 
     pub fn calculate_critical_value(&self) -> Vec<f64> {
         let mut critical_values: Vec<f64> = Vec::new();
-        let n = self.get_data().len() - 1 - self.get_lag() as usize;
+        let n = self.get_data().iter().filter(|v| !v.is_nan()).count() - 1 - self.get_lag() as usize;
         for level in ["1%", "5%", "10%"].iter() {
             let c_hat = calculate_critical_values(n as u8, &self.get_equation(), level);
             critical_values.push(c_hat);
@@ -58,14 +59,22 @@ impl AugmentedDickeyFuller {
         if lag_values[0].len() as usize != x.len() as usize {
             return lag_values[0].len() as f64 / x.len() as f64;
         }
+
+        // Drop the rows with missing values (NaN)
+        let lag_count = lag_values.len();
+        let mut columns: Vec<Vec<f64>> = lag_values.clone();
+        columns.push(t);
+        columns.push(x);
+        columns.push(y);
+        let rows = complete_cases(columns);
+        let lag_values: Vec<Vec<f64>> = rows[0..lag_count].to_vec();
+        let (t, x, y) = (rows[lag_count].clone(), rows[lag_count + 1].clone(), rows[lag_count + 2].clone());
         let (b, se, b_vec, se_vec, stat_test_vec, p_value_vec,r_square) = match self.get_equation().as_str() {
             "no_trend" => {
                 let mut x_matriks: Vec<Vec<f64>> = lag_values.clone();
                 x_matriks.push(x.clone());
     
-                // Konversi ke nilai JS; jika gagal, panik dengan pesan jelas
-                let x_matriks_js = serde_wasm_bindgen::to_value(&x_matriks).expect("Gagal mengkonversi x_matriks ke JS value");
-                let mut reg = MultipleLinearRegression::new(x_matriks_js, y.clone());
+                let mut reg = MultipleLinearRegression::from_columns(x_matriks.clone(), y.clone());
                 reg.calculate_regression();
                 let b = reg.get_beta();
                 let se = reg.calculate_standard_error();
@@ -79,9 +88,7 @@ impl AugmentedDickeyFuller {
                 x_matriks.push(t.clone());
                 x_matriks.push(x.clone());
     
-                // Konversi ke nilai JS; jika gagal, panik dengan pesan jelas
-                let x_matriks_js = serde_wasm_bindgen::to_value(&x_matriks).expect("Gagal mengkonversi x_matriks ke JS value");
-                let mut reg = MultipleLinearRegression::new(x_matriks_js, y.clone());
+                let mut reg = MultipleLinearRegression::from_columns(x_matriks.clone(), y.clone());
                 reg.calculate_regression();
                 let b = reg.get_beta();
                 let se = reg.calculate_standard_error();
@@ -2,6 +2,7 @@ use wasm_bindgen::prelude::*;
 use crate::DickeyFuller;
 use crate::{calculate_p_value, calculate_critical_values, MultipleLinearRegression, NoInterceptLinearRegression, SimpleLinearRegression};
 use crate::{first_difference, second_difference};
+use crate::time_series::missing_value::complete_cases::complete_cases;
 
 #[wasm_bindgen]
 impl DickeyFuller{
@@ -14,7 +15,7 @@ impl DickeyFuller{
     // Calculate Critical Value
     pub fn calculate_critical_value(&self) -> Vec<f64> {
         let mut critical_values: Vec<f64> = Vec::new();
-        let n = self.get_data().len() - 1;
+        let n = self.get_data().iter().filter(|v| !v.is_nan()).count() - 1;
         for level in ["1%", "5%", "10%"].iter() {
             let c_hat = calculate_critical_values(n as u8, &self.get_equation(), level);
             critical_values.push(c_hat);
@@ -49,6 +50,10 @@ impl DickeyFuller{
             x.push(data[i]);
             y.push(difference[i]);
         }
+
+        // Drop the rows with missing values (NaN)
+        let rows = complete_cases(vec![t, x, y]);
+        let (t, x, y) = (rows[0].clone(), rows[1].clone(), rows[2].clone());
         
         let (b, se, b_vec, se_vec, stat_test_vec, p_value_vec, r_square) = match self.get_equation().as_str() {
             "no_constant" => {
@@ -74,10 +79,7 @@ impl DickeyFuller{
             "with_trend" => {
                 // Buat matriks X dengan tren (t) dan data (x)
                 let x_matriks = vec![t.clone(), x.clone()];
-                // Konversi ke nilai JS; jika gagal, panik dengan pesan jelas
-                let x_matriks_js = serde_wasm_bindgen::to_value(&x_matriks)
-                    .expect("Gagal mengkonversi x_matriks ke JS value");
-                let mut reg = MultipleLinearRegression::new(x_matriks_js, y.clone());
+                let mut reg = MultipleLinearRegression::from_columns(x_matriks.clone(), y.clone());
                 reg.calculate_regression();
                 let b = reg.get_beta();
                 let se = reg.calculate_standard_error();